@@ -0,0 +1,88 @@
+use crate::events::GameEvent;
+use crate::player::Player;
+
+// Zeitfenster, über das Schaden und Kills gemittelt werden (exponentiell abklingend)
+const WINDOW: f32 = 10.0;
+// Kills pro Sekunde, ab denen der Spieler als überlegen gilt
+const REFERENCE_KILL_RATE: f32 = 1.5;
+
+// Spannungsband, in dem der Spieler gehalten werden soll
+const TENSION_LOW: f32 = 0.35;
+const TENSION_HIGH: f32 = 0.6;
+
+// Wie schnell die Intensität nachgeführt wird (pro Sekunde)
+const RAMP_UP_SPEED: f32 = 0.02;
+const RAMP_DOWN_SPEED: f32 = 0.06; // Entlastung greift schneller als Druck
+
+pub const MIN_INTENSITY: f32 = 0.6;
+pub const MAX_INTENSITY: f32 = 1.6;
+
+// Optionaler Director, der statt nur auf die Zeit auf die Leistung des Spielers schaut.
+// Wenig Druck → Intensität steigt, zu viel Druck → sie sinkt wieder.
+pub struct AdaptiveDirector {
+    pub intensity: f32, // 1.0 = Tempo des Stage-Scripts
+    pub tension: f32,   // 0 = entspannt, 1 = kurz vor dem Tod
+    recent_damage: f32,
+    recent_kills: f32,
+}
+
+impl AdaptiveDirector {
+    pub fn new() -> Self {
+        Self {
+            intensity: 1.0,
+            tension: 0.0,
+            recent_damage: 0.0,
+            recent_kills: 0.0,
+        }
+    }
+
+    // Subscriber: Schaden und Kills fließen in die Bewertung ein
+    pub fn record(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::PlayerDamaged { damage } => self.recent_damage += damage,
+            GameEvent::DebrisDestroyed { .. }
+            | GameEvent::GunshipDestroyed { .. }
+            | GameEvent::BossDefeated { .. } => self.recent_kills += 1.0,
+            _ => {}
+        }
+    }
+
+    pub fn update(&mut self, dt: f32, player: &Player) {
+        let decay = (-dt / WINDOW).exp();
+        self.recent_damage *= decay;
+        self.recent_kills *= decay;
+
+        let pressure = (self.recent_damage / player.max_hp.max(1.0)).min(1.0);
+        let danger = (1.0 - player.hp / player.max_hp.max(1.0)).clamp(0.0, 1.0);
+        let kill_rate = self.recent_kills / WINDOW;
+        let dominance = (kill_rate / REFERENCE_KILL_RATE).min(1.0);
+        self.tension = (0.5 * pressure + 0.35 * danger + 0.4 * (1.0 - dominance)).clamp(0.0, 1.0);
+
+        if self.tension < TENSION_LOW {
+            self.intensity += RAMP_UP_SPEED * dt;
+        } else if self.tension > TENSION_HIGH {
+            self.intensity -= RAMP_DOWN_SPEED * dt;
+        }
+        self.intensity = self.intensity.clamp(MIN_INTENSITY, MAX_INTENSITY);
+    }
+
+    // Faktor auf die Sekunden zwischen Spawns
+    pub fn spawn_rate_scale(&self) -> f32 {
+        1.0 / self.intensity
+    }
+
+    // Faktor auf die Debris-Geschwindigkeit, gedämpft damit es fair bleibt
+    pub fn debris_speed_scale(&self) -> f32 {
+        1.0 + (self.intensity - 1.0) * 0.5
+    }
+
+    // Faktor auf die Zeit zwischen Items: wer kämpft, bekommt öfter Hilfe
+    pub fn item_interval_scale(&self) -> f32 {
+        self.intensity
+    }
+
+    // 0..1 für Musik und Anzeige
+    pub fn normalized(&self) -> f32 {
+        (self.intensity - MIN_INTENSITY) / (MAX_INTENSITY - MIN_INTENSITY)
+    }
+}
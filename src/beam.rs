@@ -0,0 +1,215 @@
+use macroquad::prelude::*;
+
+use crate::bullet::{CRIT_MULTIPLIER, DEFLECT_THRESHOLD, effective_armor};
+use crate::events::{EventQueue, GameEvent};
+use crate::help_fn::lerp;
+use crate::player::Player;
+use crate::rng::Rng;
+use crate::world::Arena;
+
+// Energie des Strahls: leert sich beim Feuern, lädt sonst nach
+const MAX_ENERGY: f32 = 100.0;
+const ENERGY_DRAIN: f32 = 40.0; // pro Sekunde Dauerfeuer (~2.5s bis leer)
+const ENERGY_REGEN: f32 = 30.0;
+// Nach komplettem Leerlaufen erst ab diesem Stand wieder zünden
+const RESTART_ENERGY: f32 = 25.0;
+
+// Schaden pro Sekunde am getroffenen Ziel, vor Skills und Panzerung
+const BEAM_DPS: f32 = 14.0;
+
+// Hitze pro Sekunde Dauerfeuer, falls das Hitzemodell aktiv ist
+pub const BEAM_HEAT_PER_SECOND: f32 = 0.4;
+
+// Der Schaden kommt in Pulsen. Jeder Puls zählt wie ein Schuss-Treffer
+// (Crit-Wurf, BulletHit-Event, Funken an den Trefferpunkten).
+const PULSE_INTERVAL: f32 = 0.1;
+
+// Ziel auf dem Strahl, Indizes beziehen sich auf die Listen in World
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BeamTarget {
+    Debris(usize),
+    Gunship(usize),
+    Boss,
+}
+
+// Dauerstrahl der Beam-Waffe. Lebt neben den Projektilen in World; World sammelt
+// per Raycast die Ziele auf dem Strahl, getroffen wird das erste (mit Pierce mehrere).
+pub struct Beam {
+    pub active: bool,
+    pub energy: f32,
+    pub depleted: bool, // Leer gelaufen, wartet auf RESTART_ENERGY
+    pub origin: Vec2,   // Schiffsnase im aktuellen Tick
+    pub direction: Vec2,
+    pub length: f32, // Bis zum letzten Treffer oder Rand des Spielfelds
+    pub hits: Vec<(f32, BeamTarget)>, // Abstand entlang des Strahls und Ziel
+    pub impacts: Vec<Vec2>, // Trefferpunkte des aktuellen Ticks
+    pub sparks: Vec<Vec2>, // Trefferpunkte, an denen World Funken erzeugt
+    pub pulse: bool, // In diesem Tick wird Schaden ausgeteilt
+    pulse_timer: f32,
+}
+
+impl Beam {
+    pub fn new() -> Self {
+        Self {
+            active: false,
+            energy: MAX_ENERGY,
+            depleted: false,
+            origin: Vec2::ZERO,
+            direction: Vec2::new(0.0, -1.0),
+            length: 0.0,
+            hits: Vec::new(),
+            impacts: Vec::new(),
+            sparks: Vec::new(),
+            pulse: false,
+            pulse_timer: 0.0,
+        }
+    }
+
+    pub fn energy_progress(&self) -> f32 {
+        self.energy / MAX_ENERGY
+    }
+
+    // firing: Feuertaste gedrückt und Beam ausgerüstet. Danach meldet World
+    // die Ziele per add_hit und schließt mit finish_hits ab.
+    pub fn update(&mut self, dt: f32, firing: bool, player: &Player, arena: Arena) {
+        self.hits.clear();
+        self.impacts.clear();
+        self.sparks.clear();
+        self.pulse = false;
+
+        if self.depleted && self.energy >= RESTART_ENERGY {
+            self.depleted = false;
+        }
+        self.active = firing && !self.depleted;
+        if !self.active {
+            self.energy = (self.energy + ENERGY_REGEN * dt).min(MAX_ENERGY);
+            return;
+        }
+
+        self.energy -= ENERGY_DRAIN * dt;
+        if self.energy <= 0.0 {
+            self.energy = 0.0;
+            self.depleted = true;
+        }
+
+        // Strahl von der Schiffsnase entlang der Blickrichtung
+        self.direction = player.aim_direction();
+        self.origin = player.get_position() + self.direction * player.size;
+        self.length = arena.max_side() * 1.5;
+
+        self.pulse_timer -= dt;
+        if self.pulse_timer <= 0.0 {
+            self.pulse_timer = PULSE_INTERVAL;
+            self.pulse = true;
+        }
+    }
+
+    // Ziel in Abstand t entlang des Strahls melden
+    pub fn add_hit(&mut self, t: f32, target: BeamTarget) {
+        if t <= self.length {
+            self.hits.push((t, target));
+        }
+    }
+
+    // Nur die vordersten Ziele behalten, Pierce lässt den Strahl durch weitere gehen
+    pub fn finish_hits(&mut self, player: &Player) {
+        self.hits.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let max_targets = 1 + player.bullet_pierce_count as usize;
+        self.hits.truncate(max_targets);
+        if self.hits.len() == max_targets
+            && let Some((t, _)) = self.hits.last()
+        {
+            self.length = *t;
+        }
+        for (t, _) in &self.hits {
+            self.impacts.push(self.origin + self.direction * *t);
+        }
+    }
+
+    // Schaden eines Pulses am Ziel in Abstand t. Meldet den Treffer wie ein Schuss.
+    pub fn pulse_damage(
+        &mut self,
+        t: f32,
+        armor: f32,
+        player: &Player,
+        rng: &mut Rng,
+        events: &mut EventQueue,
+    ) -> f32 {
+        let is_critical = rng.chance(player.crit_chance);
+        let mut damage = BEAM_DPS * PULSE_INTERVAL * player.damage_multiplier;
+        if is_critical {
+            damage *= CRIT_MULTIPLIER;
+        }
+        let armor = effective_armor(armor, player.armor_penetration);
+        damage *= 1.0 - armor;
+
+        let point = self.origin + self.direction * t;
+        events.push(GameEvent::BulletHit {
+            x: point.x,
+            y: point.y,
+            damage,
+            is_critical,
+            deflected: armor > DEFLECT_THRESHOLD,
+        });
+        self.sparks.push(point);
+        damage
+    }
+
+    // Vom interpolierten Schiff aus zeichnen, damit der Strahl nicht nachhängt
+    pub fn draw(&self, player: &Player, alpha: f32) {
+        if !self.active {
+            return;
+        }
+
+        let time = get_time() as f32;
+        let origin = player.render_position(alpha) + self.direction * player.size;
+        let end = origin + self.direction * self.length;
+        let flicker = lerp(0.85, 1.0, (time * 40.0).sin() * 0.5 + 0.5);
+        let width = player.size * 0.25 * flicker;
+
+        // Äußerer Glow, Strahl und heller Kern
+        draw_line(
+            origin.x,
+            origin.y,
+            end.x,
+            end.y,
+            width * 3.0,
+            Color::new(1.0, 0.2, 0.3, 0.15),
+        );
+        draw_line(
+            origin.x,
+            origin.y,
+            end.x,
+            end.y,
+            width * 1.6,
+            Color::new(1.0, 0.35, 0.45, 0.6),
+        );
+        draw_line(
+            origin.x,
+            origin.y,
+            end.x,
+            end.y,
+            width * 0.6,
+            Color::new(1.0, 0.9, 0.9, 0.95),
+        );
+
+        // Mündungsfeuer
+        draw_circle(
+            origin.x,
+            origin.y,
+            width * 1.5,
+            Color::new(1.0, 0.6, 0.6, 0.7),
+        );
+
+        // Glühende Trefferpunkte
+        for impact in &self.impacts {
+            draw_circle(
+                impact.x,
+                impact.y,
+                width * 2.2 * flicker,
+                Color::new(1.0, 0.5, 0.3, 0.5),
+            );
+            draw_circle(impact.x, impact.y, width, Color::new(1.0, 0.95, 0.8, 0.9));
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use macroquad::prelude::*;
+
+use crate::bullet::Bullet;
+use crate::help_fn::lerp;
+use crate::rng::Rng;
+use crate::world::Arena;
+
+pub const BOSS_SCORE: i32 = 5000; // pro Boss-Nummer
+pub const BOSS_SEGMENTS: usize = 3; // Abschnitte im HP-Balken, einer pro Phase
+pub const BOSS_CONTACT_DAMAGE: f32 = 1.0;
+const BOSS_BASE_HP: f32 = 250.0;
+const BOSS_ARMOR: f32 = 0.3;
+const RING_BULLETS: usize = 16;
+const CONTACT_COOLDOWN: f32 = 1.0;
+
+// Angriffsphase, ergibt sich aus dem verlorenen HP-Abschnitt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BossPhase {
+    BulletRing,   // Nur Schussringe
+    DebrisSummon, // Ringe und herbeigerufene Debris
+    Charge,       // Ringe und Sturmangriffe auf den Spieler
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BossMovement {
+    Entering,
+    Hovering,
+    Charging { target: Vec2 },
+    Returning,
+}
+
+// Großer Gegner, der alle paar Minuten auftaucht
+pub struct Boss {
+    pub x: f32,
+    pub y: f32,
+    pub prev_x: f32, // Position des vorherigen Ticks (für Interpolation)
+    pub prev_y: f32,
+    pub home_x: f32, // Schwebeposition
+    pub home_y: f32,
+    pub size: f32,
+    pub speed: f32,
+    pub hp: f32,
+    pub max_hp: f32,
+    pub armor: f32,
+    pub displayed_hp_progress: f32,
+    pub number: u32, // Der wievielte Boss des Runs
+    pub movement: BossMovement,
+    pub attack_timer: f32,
+    pub attack_count: u32,
+    pub sway_time: f32,
+    pub pending_summons: u8, // Debris, die World für den Boss spawnen soll
+    pub contact_cooldown: f32,
+    pub time_scale: f32, // SlowMotion/TimeFreeze, wie bei den Kanonenbooten
+}
+
+impl Boss {
+    pub fn new(arena: Arena, number: u32) -> Self {
+        let size = arena.min_side() * 0.1;
+        let x = arena.width / 2.0;
+        let y = -size * 2.0;
+        let max_hp = BOSS_BASE_HP * (1.0 + 0.5 * (number - 1) as f32);
+
+        Boss {
+            x,
+            y,
+            prev_x: x,
+            prev_y: y,
+            home_x: x,
+            home_y: arena.height * 0.2,
+            size,
+            speed: arena.min_side() * 0.2,
+            hp: max_hp,
+            max_hp,
+            armor: BOSS_ARMOR,
+            displayed_hp_progress: 1.0,
+            number,
+            movement: BossMovement::Entering,
+            attack_timer: 2.0,
+            attack_count: 0,
+            sway_time: 0.0,
+            pending_summons: 0,
+            contact_cooldown: 0.0,
+            time_scale: 1.0,
+        }
+    }
+
+    pub fn phase(&self) -> BossPhase {
+        let lost = ((1.0 - self.hp.max(0.0) / self.max_hp) * BOSS_SEGMENTS as f32) as usize;
+        match lost.min(BOSS_SEGMENTS - 1) {
+            0 => BossPhase::BulletRing,
+            1 => BossPhase::DebrisSummon,
+            _ => BossPhase::Charge,
+        }
+    }
+
+    // Gibt true zurück, wenn der Boss besiegt ist
+    pub fn update(
+        &mut self,
+        dt: f32,
+        player_pos: Vec2,
+        arena: Arena,
+        bullets: &mut Vec<Bullet>,
+        rng: &mut Rng,
+    ) -> bool {
+        self.prev_x = self.x;
+        self.prev_y = self.y;
+        let dt_scaled = dt * self.time_scale;
+        self.contact_cooldown -= dt;
+
+        match self.movement {
+            BossMovement::Entering => {
+                if self.move_towards(Vec2::new(self.home_x, self.home_y), self.speed, dt_scaled) {
+                    self.movement = BossMovement::Hovering;
+                }
+            }
+            BossMovement::Hovering => {
+                // Langsam hin und her schweben
+                self.sway_time += dt_scaled;
+                let sway = Vec2::new(
+                    self.home_x + (self.sway_time * 0.6).sin() * arena.width * 0.25,
+                    self.home_y,
+                );
+                self.move_towards(sway, self.speed, dt_scaled);
+
+                self.attack_timer -= dt_scaled;
+                if self.attack_timer <= 0.0 {
+                    self.attack(player_pos, arena, bullets, rng);
+                }
+            }
+            BossMovement::Charging { target } => {
+                if self.move_towards(target, self.speed * 4.0, dt_scaled) {
+                    self.movement = BossMovement::Returning;
+                }
+            }
+            BossMovement::Returning => {
+                if self.move_towards(Vec2::new(self.x, self.home_y), self.speed * 1.5, dt_scaled) {
+                    self.home_x = self.x.clamp(arena.width * 0.3, arena.width * 0.7);
+                    self.sway_time = 0.0;
+                    self.movement = BossMovement::Hovering;
+                }
+            }
+        }
+
+        // HP-Balken animieren
+        let target_progress = self.hp.max(0.0) / self.max_hp;
+        self.displayed_hp_progress = lerp(self.displayed_hp_progress, target_progress, dt * 8.0);
+
+        self.is_destroyed()
+    }
+
+    // Bewegt sich zum Ziel, gibt true zurück, wenn es erreicht ist
+    fn move_towards(&mut self, target: Vec2, speed: f32, dt: f32) -> bool {
+        let to_target = Vec2::new(target.x - self.x, target.y - self.y);
+        let distance = to_target.length();
+        let step = (speed * dt).min(distance);
+        if distance > 0.0 {
+            self.x += to_target.x / distance * step;
+            self.y += to_target.y / distance * step;
+        }
+        distance - step <= 1.0
+    }
+
+    fn attack(&mut self, player_pos: Vec2, arena: Arena, bullets: &mut Vec<Bullet>, rng: &mut Rng) {
+        self.attack_count += 1;
+        let special = self.attack_count.is_multiple_of(2);
+
+        match self.phase() {
+            BossPhase::BulletRing => {
+                self.fire_ring(arena, bullets);
+                self.attack_timer = 2.0;
+            }
+            BossPhase::DebrisSummon => {
+                if special {
+                    self.pending_summons += rng.gen_range(2, 4) as u8;
+                } else {
+                    self.fire_ring(arena, bullets);
+                }
+                self.attack_timer = 1.8;
+            }
+            BossPhase::Charge => {
+                if special {
+                    // Auf die aktuelle Spielerposition zustürmen
+                    self.movement = BossMovement::Charging { target: player_pos };
+                } else {
+                    self.fire_ring(arena, bullets);
+                }
+                self.attack_timer = 1.5;
+            }
+        }
+    }
+
+    // Ring aus Schüssen, bei jedem Angriff leicht versetzt
+    fn fire_ring(&self, arena: Arena, bullets: &mut Vec<Bullet>) {
+        let offset = self.attack_count as f32 * 0.2;
+        for i in 0..RING_BULLETS {
+            let angle = offset + i as f32 / RING_BULLETS as f32 * std::f32::consts::TAU;
+            let nose = angle - std::f32::consts::FRAC_PI_2;
+            bullets.push(Bullet::new_enemy(
+                self.x + nose.cos() * self.size,
+                self.y + nose.sin() * self.size,
+                angle,
+                arena,
+            ));
+        }
+    }
+
+    // Vom Boss angeforderte Debris abholen
+    pub fn take_summons(&mut self) -> u8 {
+        std::mem::take(&mut self.pending_summons)
+    }
+
+    // Kontaktschaden mit Abklingzeit, gibt true zurück, wenn er zählt
+    pub fn try_contact(&mut self, player_pos: Vec2, player_size: f32) -> bool {
+        let distance = (Vec2::new(self.x, self.y) - player_pos).length();
+        if self.contact_cooldown <= 0.0 && distance < self.size + player_size {
+            self.contact_cooldown = CONTACT_COOLDOWN;
+            return true;
+        }
+        false
+    }
+
+    pub fn draw(&self, alpha: f32) {
+        let time = get_time() as f32;
+        let pos = Vec2::new(
+            lerp(self.prev_x, self.x, alpha),
+            lerp(self.prev_y, self.y, alpha),
+        );
+
+        let (hull_color, glow_color) = match self.phase() {
+            BossPhase::BulletRing => (
+                Color::new(0.45, 0.15, 0.35, 1.0),
+                Color::new(1.0, 0.4, 0.9, 0.8),
+            ),
+            BossPhase::DebrisSummon => (
+                Color::new(0.5, 0.3, 0.1, 1.0),
+                Color::new(1.0, 0.7, 0.2, 0.8),
+            ),
+            BossPhase::Charge => (
+                Color::new(0.55, 0.1, 0.1, 1.0),
+                Color::new(1.0, 0.2, 0.2, 0.8),
+            ),
+        };
+
+        // Äußerer Ring aus Panzerplatten
+        draw_poly(pos.x, pos.y, 8, self.size, time * 10.0, hull_color);
+        draw_poly_lines(
+            pos.x,
+            pos.y,
+            8,
+            self.size,
+            time * 10.0,
+            2.0,
+            Color::new(0.8, 0.8, 0.8, 0.6),
+        );
+
+        // Inneres Auge, pulsiert
+        let pulse = 1.0 + 0.15 * (time * 4.0).sin();
+        draw_circle(pos.x, pos.y, self.size * 0.45 * pulse, glow_color);
+        draw_circle(pos.x, pos.y, self.size * 0.2, WHITE);
+
+        // Warnung während des Sturmangriffs
+        if let BossMovement::Charging { .. } = self.movement {
+            draw_circle_lines(
+                pos.x,
+                pos.y,
+                self.size * 1.2,
+                3.0,
+                Color::new(1.0, 0.2, 0.2, 0.7),
+            );
+        }
+    }
+
+    pub fn explosion_colors() -> [Color; 4] {
+        [
+            Color::new(1.0, 0.4, 0.9, 1.0),
+            Color::new(1.0, 0.6, 0.2, 1.0),
+            Color::new(1.0, 1.0, 0.8, 1.0),
+            Color::new(0.6, 0.6, 0.6, 1.0),
+        ]
+    }
+
+    pub fn take_damage(&mut self, damage: f32) -> bool {
+        self.hp -= damage;
+        self.hp <= 0.0
+    }
+
+    pub fn is_destroyed(&self) -> bool {
+        self.hp <= 0.0
+    }
+}
@@ -1,10 +1,51 @@
+use crate::boss::Boss;
 use crate::debris::Debris;
+use crate::enemy::Gunship;
+use crate::events::{EventQueue, GameEvent};
+use crate::help_fn::lerp;
 use crate::player::Player;
+use crate::rng::Rng;
+use crate::spatial_grid::SpatialGrid;
+use crate::weapon::WeaponType;
+use crate::world::Arena;
 use macroquad::prelude::*;
 
+// Höchstens so viel Schaden kann Panzerung schlucken
+const MAX_ARMOR: f32 = 0.9;
+// Ab dieser wirksamen Panzerung gilt ein Treffer als abgeprallt
+pub const DEFLECT_THRESHOLD: f32 = 0.4;
+// Schadensfaktor kritischer Treffer
+pub const CRIT_MULTIPLIER: f32 = 2.0;
+
+// Wirksame Panzerung nach Abzug des Panzerdurchschlags, gilt für Schüsse und Beam
+pub fn effective_armor(armor: f32, armor_penetration: f32) -> f32 {
+    (armor - armor_penetration).clamp(0.0, MAX_ARMOR)
+}
+
+// Wer geschossen hat. Spielerschüsse treffen Debris und Gegner,
+// gegnerische Schüsse nur den Spieler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Faction {
+    Player,
+    Enemy,
+}
+
+// Aussehen eines Spielerschusses, kommt von der Waffe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulletStyle {
+    Standard,
+    Pulse,
+    Missile,
+    Rail,
+    Charged,
+    Plasma,
+}
+
 pub struct Bullet {
     pub x: f32,
     pub y: f32,
+    pub prev_x: f32, // Position des vorherigen Ticks (für Interpolation)
+    pub prev_y: f32,
     pub velocity_x: f32,
     pub velocity_y: f32,
     pub size: f32,
@@ -18,19 +59,32 @@ pub struct Bullet {
     pub is_critical: bool,
     pub lifetime: f32,
     pub max_lifetime: f32,
+    pub faction: Faction,
+    pub style: BulletStyle,
+    pub hit_targets: Vec<HitTarget>, // Schon getroffene Ziele, jedes nur einmal treffen
+}
+
+// Ziel eines Treffers, Debris und Kanonenboote über ihre Id
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitTarget {
+    Debris(u32),
+    Gunship(u32),
+    Boss,
 }
 
 impl Bullet {
-    pub fn new(x: f32, y: f32, angle: f32) -> Self {
-        let base_speed = screen_height() * 1.0;
+    pub fn new(x: f32, y: f32, angle: f32, arena: Arena) -> Self {
+        let base_speed = arena.height;
         let shoot_angle = angle - std::f32::consts::FRAC_PI_2;
 
         Bullet {
             x,
             y,
+            prev_x: x,
+            prev_y: y,
             velocity_x: shoot_angle.cos() * base_speed,
             velocity_y: shoot_angle.sin() * base_speed,
-            size: screen_width().min(screen_height()) * 0.01,
+            size: arena.min_side() * 0.01,
             damage: 2.5,
             speed: base_speed,
             pierce_count: 0,
@@ -41,43 +95,121 @@ impl Bullet {
             is_critical: false,
             lifetime: 0.0,
             max_lifetime: 5.0, // 5 seconds max lifetime
+            faction: Faction::Player,
+            style: BulletStyle::Standard,
+            hit_targets: Vec::new(),
         }
     }
 
-    pub fn new_with_player_skills(x: f32, y: f32, angle: f32, player: &Player) -> Self {
-        let mut bullet = Self::new(x, y, angle);
+    // Flugrichtung mit der aktuellen Geschwindigkeit setzen
+    pub fn set_direction(&mut self, angle: f32) {
+        let shoot_angle = angle - std::f32::consts::FRAC_PI_2;
+        self.velocity_x = shoot_angle.cos() * self.speed;
+        self.velocity_y = shoot_angle.sin() * self.speed;
+    }
 
-        // Apply player skill modifiers
+    // Langsamerer gegnerischer Schuss
+    pub fn new_enemy(x: f32, y: f32, angle: f32, arena: Arena) -> Self {
+        let mut bullet = Self::new(x, y, angle, arena);
+        let shoot_angle = angle - std::f32::consts::FRAC_PI_2;
+
+        bullet.speed = arena.height * 0.45;
+        bullet.velocity_x = shoot_angle.cos() * bullet.speed;
+        bullet.velocity_y = shoot_angle.sin() * bullet.speed;
+        bullet.size = arena.min_side() * 0.008;
+        bullet.damage = 1.0;
+        bullet.faction = Faction::Enemy;
+        bullet
+    }
+
+    // Einziger Weg für Spielerschüsse: Grundwerte der Waffe plus alle Combat-Skills
+    pub fn new_with_player_skills(
+        weapon: WeaponType,
+        x: f32,
+        y: f32,
+        angle: f32,
+        player: &Player,
+        arena: Arena,
+        rng: &mut Rng,
+    ) -> Self {
+        let mut bullet = weapon.projectile(x, y, angle, arena);
+
+        // Apply player skill modifiers on top of the weapon stats
         bullet.damage *= player.damage_multiplier;
         bullet.speed *= player.bullet_speed_multiplier;
         bullet.size *= player.bullet_size_multiplier;
-        bullet.pierce_count = player.bullet_pierce_count;
-        bullet.remaining_pierces = player.bullet_pierce_count;
-        bullet.explosion_damage = player.bullet_explosion_damage;
-        bullet.armor_penetration = player.armor_penetration;
-        bullet.homing_strength = player.bullet_homing_strength;
+        bullet.pierce_count += player.bullet_pierce_count;
+        bullet.remaining_pierces += player.bullet_pierce_count;
+        bullet.explosion_damage += player.bullet_explosion_damage;
+        bullet.armor_penetration += player.armor_penetration;
+        bullet.homing_strength += player.bullet_homing_strength;
 
         // Check for critical hit
-        if rand::gen_range(0.0, 1.0) < player.crit_chance {
+        if rng.chance(player.crit_chance) {
             bullet.is_critical = true;
-            bullet.damage *= 2.0; // Critical hits do double damage
+            bullet.damage *= CRIT_MULTIPLIER;
         }
 
         // Update velocity with new speed
-        let shoot_angle = angle - std::f32::consts::FRAC_PI_2;
-        bullet.velocity_x = shoot_angle.cos() * bullet.speed;
-        bullet.velocity_y = shoot_angle.sin() * bullet.speed;
+        bullet.set_direction(angle);
 
         bullet
     }
 
-    pub fn update(&mut self, debris: &Vec<Debris>) {
-        let dt = get_frame_time();
+    // Aufgeladener Schuss, charge von 0 bis 1. Größe, Schaden, Pierce und Explosion
+    // wachsen mit der Ladung (der Explosionsradius hängt an der Größe). PlasmaCannon
+    // macht daraus einen deutlich stärkeren Mega-Schuss.
+    pub fn new_charged(player: &Player, charge: f32, arena: Arena, rng: &mut Rng) -> Self {
+        let nose = player.nose();
+        let mut bullet = Self::new_with_player_skills(
+            WeaponType::Blaster,
+            nose.x,
+            nose.y,
+            player.aim_angle(),
+            player,
+            arena,
+            rng,
+        );
+
+        let (size, damage, pierce, explosion) = if player.plasma_cannon {
+            (
+                1.5 + 3.5 * charge,
+                4.0 + 16.0 * charge,
+                2.0 + 6.0 * charge,
+                1.0 + 1.5 * charge,
+            )
+        } else {
+            (
+                1.0 + 2.0 * charge,
+                2.0 + 4.0 * charge,
+                3.0 * charge,
+                0.5 * charge,
+            )
+        };
+        bullet.size *= size;
+        bullet.damage *= damage;
+        bullet.pierce_count += pierce as u8;
+        bullet.remaining_pierces += pierce as u8;
+        bullet.explosion_damage += explosion;
+        bullet.speed *= 0.8;
+        bullet.set_direction(player.aim_angle());
+        bullet.style = if player.plasma_cannon {
+            BulletStyle::Plasma
+        } else {
+            BulletStyle::Charged
+        };
+        bullet
+    }
+
+    // grid: Raster über `debris` für die Zielsuche
+    pub fn update(&mut self, dt: f32, debris: &[Debris], grid: &SpatialGrid) {
         self.lifetime += dt;
+        self.prev_x = self.x;
+        self.prev_y = self.y;
 
         // Apply homing if enabled
         if self.homing_strength > 0.0 && !debris.is_empty() {
-            self.apply_homing(debris, dt);
+            self.apply_homing(debris, grid, dt);
         }
 
         // Update position
@@ -85,21 +217,15 @@ impl Bullet {
         self.y += self.velocity_y * dt;
     }
 
-    fn apply_homing(&mut self, debris: &Vec<Debris>, dt: f32) {
+    fn apply_homing(&mut self, debris: &[Debris], grid: &SpatialGrid, dt: f32) {
         // Find closest debris
-        let mut closest_distance = f32::INFINITY;
-        let mut closest_debris: Option<&Debris> = None;
-
-        for debris_piece in debris {
-            let dx = debris_piece.x - self.x;
-            let dy = debris_piece.y - self.y;
-            let distance = (dx * dx + dy * dy).sqrt();
-
-            if distance < closest_distance {
-                closest_distance = distance;
-                closest_debris = Some(debris_piece);
-            }
-        }
+        let closest_debris = grid
+            .nearest(self.x, self.y, |i| {
+                let dx = debris[i].x - self.x;
+                let dy = debris[i].y - self.y;
+                (dx * dx + dy * dy).sqrt()
+            })
+            .map(|i| &debris[i]);
 
         if let Some(target) = closest_debris {
             // Calculate direction to target
@@ -119,115 +245,196 @@ impl Bullet {
         }
     }
 
-    pub fn draw(&self) {
+    pub fn draw(&self, alpha: f32) {
         let time = get_time() as f32;
-        let pulse_base = 1.0 + 0.15 * (time * 8.0 + self.x * 0.1 + self.y * 0.1).sin();
+        let pos = Vec2::new(
+            lerp(self.prev_x, self.x, alpha),
+            lerp(self.prev_y, self.y, alpha),
+        );
+        let pulse_base = 1.0 + 0.15 * (time * 8.0 + pos.x * 0.1 + pos.y * 0.1).sin();
 
-        // Critical bullets have different visual effects
-        let (core_color, glow_color, spark_color) = if self.is_critical {
+        // Enemy and critical bullets have different visual effects
+        let (core_color, glow_color, spark_color) = if self.faction == Faction::Enemy {
+            (
+                Color::new(1.0, 0.4, 0.9, 1.0), // Magenta core for enemy shots
+                Color::new(0.9, 0.2, 0.6, 0.4), // Magenta glow
+                Color::new(1.0, 0.5, 0.8, 0.6), // Magenta sparks
+            )
+        } else if self.is_critical {
             (
                 Color::new(1.0, 0.3, 0.3, 1.0), // Red core for crits
                 Color::new(1.0, 0.5, 0.5, 0.4), // Red glow
                 Color::new(1.0, 0.7, 0.7, 0.6), // Red sparks
             )
         } else {
-            (
-                Color::new(1.0, 1.0, 0.9, 0.9), // Normal white-yellow core
-                Color::new(0.5, 0.9, 1.0, 0.4), // Blue glow
-                Color::new(0.8, 0.9, 1.0, 0.6), // Blue sparks
-            )
+            match self.style {
+                BulletStyle::Standard => (
+                    Color::new(1.0, 1.0, 0.9, 0.9), // Normal white-yellow core
+                    Color::new(0.5, 0.9, 1.0, 0.4), // Blue glow
+                    Color::new(0.8, 0.9, 1.0, 0.6), // Blue sparks
+                ),
+                BulletStyle::Pulse => (
+                    Color::new(0.8, 1.0, 0.9, 0.9), // Green-white core
+                    Color::new(0.3, 1.0, 0.6, 0.4), // Green glow
+                    Color::new(0.6, 1.0, 0.8, 0.6), // Green sparks
+                ),
+                BulletStyle::Missile => (
+                    Color::new(1.0, 0.8, 0.5, 1.0), // Orange core
+                    Color::new(1.0, 0.5, 0.1, 0.4), // Orange glow
+                    Color::new(1.0, 0.7, 0.3, 0.6), // Exhaust sparks
+                ),
+                BulletStyle::Rail => (
+                    Color::new(0.95, 0.85, 1.0, 1.0), // Violet-white core
+                    Color::new(0.8, 0.5, 1.0, 0.4),   // Violet glow
+                    Color::new(0.9, 0.7, 1.0, 0.6),   // Violet sparks
+                ),
+                BulletStyle::Charged => (
+                    Color::new(0.85, 0.95, 1.0, 1.0), // Ice-white core
+                    Color::new(0.4, 0.7, 1.0, 0.5),   // Deep blue glow
+                    Color::new(0.7, 0.9, 1.0, 0.7),   // Blue sparks
+                ),
+                BulletStyle::Plasma => (
+                    Color::new(0.9, 1.0, 0.9, 1.0), // Green-white core
+                    Color::new(0.3, 1.0, 0.4, 0.5), // Plasma glow
+                    Color::new(0.6, 1.0, 0.6, 0.8), // Plasma sparks
+                ),
+            }
         };
 
+        // Railgun: langer Streifen entgegen der Flugrichtung
+        if self.style == BulletStyle::Rail {
+            let dir = Vec2::new(self.velocity_x, self.velocity_y).normalize_or_zero();
+            let tail = pos - dir * self.size * 12.0;
+            draw_line(
+                tail.x,
+                tail.y,
+                pos.x,
+                pos.y,
+                self.size * 1.2,
+                Color::new(glow_color.r, glow_color.g, glow_color.b, 0.5),
+            );
+        }
+
         // Piercing bullets have additional visual effects
         let pierce_multiplier = if self.pierce_count > 0 { 1.3 } else { 1.0 };
         let pulse = pulse_base * pierce_multiplier;
 
         // Äußerer Glow (angepasst für verschiedene Bullet-Typen)
         draw_circle(
-            self.x,
-            self.y,
+            pos.x,
+            pos.y,
             self.size * 2.2 * pulse,
             Color::new(glow_color.r, glow_color.g, glow_color.b, 0.2),
         );
 
         // Mittlerer Glow
-        draw_circle(self.x, self.y, self.size * 1.6 * pulse, glow_color);
+        draw_circle(pos.x, pos.y, self.size * 1.6 * pulse, glow_color);
 
         // Innerer Kern
-        draw_circle(self.x, self.y, self.size * 1.1, core_color);
+        draw_circle(pos.x, pos.y, self.size * 1.1, core_color);
 
         // Zentraler Kern
-        draw_circle(self.x, self.y, self.size * 0.6, WHITE);
+        draw_circle(pos.x, pos.y, self.size * 0.6, WHITE);
 
         // Plasma-Funken (mehr für piercing bullets)
         let spark_count = if self.pierce_count > 0 { 6 } else { 4 };
         for i in 0..spark_count {
-            let spark_time = time * 12.0 + i as f32 * (6.28 / spark_count as f32);
+            let spark_time = time * 12.0 + i as f32 * (std::f32::consts::TAU / spark_count as f32);
             let spark_distance = self.size * 1.8 * pierce_multiplier;
-            let spark_x = self.x + spark_time.cos() * spark_distance;
-            let spark_y = self.y + spark_time.sin() * spark_distance;
+            let spark_x = pos.x + spark_time.cos() * spark_distance;
+            let spark_y = pos.y + spark_time.sin() * spark_distance;
             let spark_size = self.size * 0.3 * (1.0 + 0.5 * (spark_time * 2.0).sin());
 
             draw_circle(spark_x, spark_y, spark_size, spark_color);
         }
 
+        // Rotierender Stern um kritische Schüsse
+        if self.is_critical {
+            let spin = time * 10.0;
+            for i in 0..4 {
+                let angle = spin + i as f32 * std::f32::consts::FRAC_PI_2;
+                let tip = pos + Vec2::new(angle.cos(), angle.sin()) * self.size * 2.8 * pulse;
+                draw_line(
+                    pos.x,
+                    pos.y,
+                    tip.x,
+                    tip.y,
+                    self.size * 0.35,
+                    Color::new(1.0, 0.85, 0.3, 0.8),
+                );
+            }
+        }
+
         // Explosion preview for explosive bullets
         if self.explosion_damage > 0.0 {
             let explosion_radius = self.size * 3.0;
             let explosion_alpha = 0.1 + 0.05 * (time * 10.0).sin();
             draw_circle(
-                self.x,
-                self.y,
+                pos.x,
+                pos.y,
                 explosion_radius,
                 Color::new(1.0, 0.5, 0.0, explosion_alpha),
             );
         }
     }
 
-    pub fn is_off_screen(&self) -> bool {
+    pub fn is_off_screen(&self, arena: Arena) -> bool {
         self.lifetime > self.max_lifetime
             || self.x < -self.size
-            || self.x > screen_width() + self.size
+            || self.x > arena.width + self.size
             || self.y < -self.size
-            || self.y > screen_height() + self.size
+            || self.y > arena.height + self.size
     }
 
     pub fn collides_with(&self, debris: &Debris) -> bool {
-        let dx = self.x - debris.x;
-        let dy = self.y - debris.y;
+        debris.hits_circle(self.x, self.y, self.size)
+    }
+
+    pub fn hits_circle(&self, x: f32, y: f32, radius: f32) -> bool {
+        let dx = self.x - x;
+        let dy = self.y - y;
         let distance = (dx * dx + dy * dy).sqrt();
-        distance < self.size + debris.size
+        distance < self.size + radius
     }
 
-    pub fn handle_collisions(bullets: &mut Vec<Bullet>, debris: &mut Vec<Debris>) {
+    // grid: Raster über `debris`, Positionen ändern sich hier nicht mehr
+    pub fn handle_collisions(
+        bullets: &mut Vec<Bullet>,
+        debris: &mut [Debris],
+        grid: &mut SpatialGrid,
+        events: &mut EventQueue,
+    ) {
+        let mut candidates = Vec::new();
+        let mut explosion_candidates = Vec::new();
+
         bullets.retain_mut(|bullet| {
+            if bullet.faction != Faction::Player {
+                return true;
+            }
             let mut bullet_hit = false;
 
-            for debris_piece in debris.iter_mut() {
-                if bullet.collides_with(debris_piece) {
-                    // Calculate final damage with armor penetration
-                    let mut final_damage = bullet.damage;
-
-                    // Apply armor penetration (reduces enemy damage reduction)
-                    if bullet.armor_penetration > 0.0 {
-                        // This would reduce debris armor if implemented
-                        final_damage *= 1.0 + bullet.armor_penetration;
-                    }
-
-                    debris_piece.take_damage(final_damage);
+            grid.query_circle(bullet.x, bullet.y, bullet.size, &mut candidates);
+            for &i in &candidates {
+                let debris_piece = &mut debris[i];
+                let target = HitTarget::Debris(debris_piece.id);
+                if !bullet.hit_targets.contains(&target) && bullet.collides_with(debris_piece) {
+                    // Armor reduces damage, armor penetration reduces armor
+                    let armor = bullet.effective_armor(debris_piece.armor);
+                    debris_piece.take_damage(bullet.damage * (1.0 - armor));
 
                     // Handle explosion damage
                     if bullet.explosion_damage > 0.0 {
-                        Self::apply_explosion_damage(bullet, debris);
+                        grid.query_circle(
+                            bullet.x,
+                            bullet.y,
+                            bullet.explosion_radius(),
+                            &mut explosion_candidates,
+                        );
+                        Self::apply_explosion_damage(bullet, debris, &explosion_candidates);
                     }
 
-                    bullet_hit = true;
-
-                    // Handle piercing
-                    if bullet.remaining_pierces > 0 {
-                        bullet.remaining_pierces -= 1;
-                        bullet_hit = false; // Don't destroy bullet yet
-                    }
+                    // Keep bullet if it still has pierces
+                    bullet_hit = !bullet.register_hit(target, armor, events);
 
                     break; // Only hit one enemy per frame
                 }
@@ -237,26 +444,529 @@ impl Bullet {
         });
     }
 
-    fn apply_explosion_damage(bullet: &Bullet, debris: &mut Vec<Debris>) {
-        let explosion_radius = bullet.size * 4.0; // Explosion radius
-        let explosion_damage = bullet.damage * bullet.explosion_damage;
+    // Spielerschüsse gegen Kanonenboote (wenige Ziele, daher ohne Raster)
+    pub fn handle_gunship_collisions(
+        bullets: &mut Vec<Bullet>,
+        gunships: &mut [Gunship],
+        events: &mut EventQueue,
+    ) {
+        bullets.retain_mut(|bullet| {
+            if bullet.faction != Faction::Player {
+                return true;
+            }
+
+            let Some(index) = gunships.iter().position(|g| {
+                !bullet.hit_targets.contains(&HitTarget::Gunship(g.id))
+                    && bullet.hits_circle(g.x, g.y, g.size)
+            }) else {
+                return true;
+            };
+
+            let gunship = &mut gunships[index];
+            let armor = bullet.effective_armor(gunship.armor);
+            gunship.take_damage(bullet.damage * (1.0 - armor));
+
+            // Explosion erwischt alle Kanonenboote im Radius
+            if bullet.explosion_damage > 0.0 {
+                for gunship in gunships.iter_mut() {
+                    let distance = bullet.distance_to_edge(gunship.x, gunship.y, gunship.size);
+                    gunship.take_damage(bullet.explosion_damage_at(distance, gunship.armor));
+                }
+            }
+            let target = HitTarget::Gunship(gunships[index].id);
+            bullet.register_hit(target, armor, events)
+        });
+    }
+
+    // Spielerschüsse gegen den Boss
+    pub fn handle_boss_collisions(
+        bullets: &mut Vec<Bullet>,
+        boss: &mut Boss,
+        events: &mut EventQueue,
+    ) {
+        bullets.retain_mut(|bullet| {
+            if bullet.faction != Faction::Player
+                || bullet.hit_targets.contains(&HitTarget::Boss)
+                || !bullet.hits_circle(boss.x, boss.y, boss.size)
+            {
+                return true;
+            }
+
+            let armor = bullet.effective_armor(boss.armor);
+            boss.take_damage(bullet.damage * (1.0 - armor));
+            if bullet.explosion_damage > 0.0 {
+                let distance = bullet.distance_to_edge(boss.x, boss.y, boss.size);
+                boss.take_damage(bullet.explosion_damage_at(distance, boss.armor));
+            }
+            bullet.register_hit(HitTarget::Boss, armor, events)
+        });
+    }
+
+    // Wirksame Panzerung des Ziels gegen diesen Schuss
+    pub fn effective_armor(&self, armor: f32) -> f32 {
+        effective_armor(armor, self.armor_penetration)
+    }
+
+    // Treffer melden und Pierce verbrauchen, gibt true zurück, wenn der Schuss weiterfliegt
+    fn register_hit(&mut self, target: HitTarget, armor: f32, events: &mut EventQueue) -> bool {
+        events.push(GameEvent::BulletHit {
+            x: self.x,
+            y: self.y,
+            damage: self.damage * (1.0 - armor),
+            is_critical: self.is_critical,
+            deflected: armor > DEFLECT_THRESHOLD,
+        });
+
+        // Handle piercing
+        if self.remaining_pierces > 0 {
+            self.hit_targets.push(target);
+            self.remaining_pierces -= 1;
+            return true;
+        }
+        false
+    }
+
+    fn explosion_radius(&self) -> f32 {
+        self.size * 4.0
+    }
 
-        for debris_piece in debris.iter_mut() {
+    // candidates: Indizes aus dem Raster im Explosionsradius
+    fn apply_explosion_damage(bullet: &Bullet, debris: &mut [Debris], candidates: &[usize]) {
+        for &i in candidates {
+            let debris_piece = &mut debris[i];
             let dx = debris_piece.x - bullet.x;
             let dy = debris_piece.y - bullet.y;
             let distance = (dx * dx + dy * dy).sqrt();
+            debris_piece.take_damage(bullet.explosion_damage_at(distance, debris_piece.armor));
+        }
+    }
 
-            if distance <= explosion_radius {
-                // Damage falls off with distance
-                let damage_multiplier = 1.0 - (distance / explosion_radius);
-                let final_explosion_damage = explosion_damage * damage_multiplier;
-                debris_piece.take_damage(final_explosion_damage);
-            }
+    // Explosionsschaden an einem Ziel in `distance`, 0 außerhalb des Radius
+    fn explosion_damage_at(&self, distance: f32, armor: f32) -> f32 {
+        let explosion_radius = self.explosion_radius();
+        if distance > explosion_radius {
+            return 0.0;
         }
+        // Damage falls off with distance
+        let damage_multiplier = 1.0 - (distance / explosion_radius);
+        self.damage
+            * self.explosion_damage
+            * damage_multiplier
+            * (1.0 - self.effective_armor(armor))
+    }
+
+    // Abstand bis zum Rand großer Ziele (Kanonenboote, Boss), sonst läge
+    // ihr Mittelpunkt fast immer außerhalb des Explosionsradius
+    fn distance_to_edge(&self, x: f32, y: f32, size: f32) -> f32 {
+        (Vec2::new(x - self.x, y - self.y).length() - size).max(0.0)
     }
 }
 
-// Helper function for linear interpolation
-fn lerp(a: f32, b: f32, t: f32) -> f32 {
-    a + (b - a) * t.clamp(0.0, 1.0)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::debris_kind::DebrisKind;
+    use crate::input::InputState;
+    use crate::skill_tree::SkillTreeManager;
+
+    fn arena() -> Arena {
+        Arena::logical()
+    }
+
+    // Spieler mit einer Liste freigeschalteter Skills ("Name:Punkte")
+    fn player_with(skills: &[&str]) -> Player {
+        let mut player = Player::new(arena());
+        let list: Vec<String> = skills.iter().map(|s| s.to_string()).collect();
+        SkillTreeManager::apply_skill_list(&list, &mut player);
+        player
+    }
+
+    fn shot(player: &Player, rng: &mut Rng) -> Bullet {
+        Bullet::new_with_player_skills(WeaponType::Blaster, 500.0, 500.0, 0.0, player, arena(), rng)
+    }
+
+    // Ruhendes Debris an fester Stelle
+    fn debris_at(x: f32, y: f32, armor: f32) -> Debris {
+        let mut rng = Rng::new(1);
+        let mut debris = Debris::new_fragment(
+            arena(),
+            &DebrisKind::scrap(),
+            0,
+            Vec2::new(x, y),
+            Vec2::ZERO,
+            arena().min_side() * 0.04,
+            &mut rng,
+        );
+        debris.armor = armor;
+        debris.hp = 1000.0;
+        debris.max_hp = 1000.0;
+        debris
+    }
+
+    // Einen Schuss gegen die Debris laufen lassen, gibt zurück ob er weiterfliegt
+    fn collide(bullet: Bullet, debris: &mut [Debris]) -> bool {
+        let mut bullets = vec![bullet];
+        let mut grid = SpatialGrid::new(128.0);
+        grid.rebuild(
+            arena(),
+            debris.iter().map(|d| (d.x, d.y, d.collision_radius())),
+        );
+        Bullet::handle_collisions(&mut bullets, debris, &mut grid, &mut EventQueue::new());
+        !bullets.is_empty()
+    }
+
+    // Einen Schuss mehrere Ticks durch die Debris fliegen lassen wie in World::update
+    fn fly(bullet: Bullet, debris: &mut [Debris], seconds: f32) {
+        for (i, d) in debris.iter_mut().enumerate() {
+            d.id = i as u32 + 1;
+        }
+        let mut bullets = vec![bullet];
+        let mut grid = SpatialGrid::new(128.0);
+        let dt = 1.0 / 120.0;
+        for _ in 0..(seconds / dt) as usize {
+            grid.rebuild(
+                arena(),
+                debris.iter().map(|d| (d.x, d.y, d.collision_radius())),
+            );
+            for b in bullets.iter_mut() {
+                b.update(dt, debris, &grid);
+            }
+            Bullet::handle_collisions(&mut bullets, debris, &mut grid, &mut EventQueue::new());
+        }
+    }
+
+    // Schüsse über eine feste Zeit mit gedrückter Feuertaste zählen
+    fn shots_fired(player: &mut Player, seconds: f32) -> usize {
+        let mut bullets = Vec::new();
+        let mut rng = Rng::new(7);
+        let input = InputState {
+            shoot: true,
+            ..Default::default()
+        };
+        let dt = 1.0 / 120.0;
+        for _ in 0..(seconds / dt) as usize {
+            player.update(
+                dt,
+                &input,
+                arena(),
+                &mut bullets,
+                &mut rng,
+                &mut EventQueue::new(),
+            );
+        }
+        bullets.len()
+    }
+
+    #[test]
+    fn sharpened_projectiles_increase_damage() {
+        let mut rng = Rng::new(1);
+        let base = shot(&player_with(&[]), &mut rng);
+        let skilled = shot(&player_with(&["SharpenedProjectiles:5"]), &mut rng);
+        assert!(skilled.damage > base.damage);
+    }
+
+    #[test]
+    fn focused_aim_makes_shots_faster() {
+        let mut rng = Rng::new(1);
+        let base = shot(&player_with(&[]), &mut rng);
+        let skilled = shot(&player_with(&["FocusedAim:5"]), &mut rng);
+        let speed = |b: &Bullet| Vec2::new(b.velocity_x, b.velocity_y).length();
+        assert!(speed(&skilled) > speed(&base) * 1.2);
+    }
+
+    #[test]
+    fn expanded_ammo_grows_shots() {
+        let mut rng = Rng::new(1);
+        let base = shot(&player_with(&[]), &mut rng);
+        let skilled = shot(&player_with(&["ExpandedAmmo:3"]), &mut rng);
+        assert!(skilled.size > base.size);
+    }
+
+    #[test]
+    fn piercing_shots_pass_through_debris() {
+        let mut rng = Rng::new(1);
+        // Der Schuss fliegt nach oben, die zweite Debris liegt hinter der ersten
+        let line = || vec![debris_at(500.0, 480.0, 0.0), debris_at(500.0, 300.0, 0.0)];
+
+        let mut debris = line();
+        fly(shot(&player_with(&[]), &mut rng), &mut debris, 0.3);
+        assert!(debris[0].hp < debris[0].max_hp);
+        assert_eq!(debris[1].hp, debris[1].max_hp);
+
+        let mut debris = line();
+        let skilled = shot(&player_with(&["PiercingShots:2"]), &mut rng);
+        let damage = skilled.damage;
+        fly(skilled, &mut debris, 0.3);
+        // Jede Debris genau einmal getroffen, obwohl der Schuss mehrere Ticks darin steckt
+        assert_eq!(debris[0].hp, debris[0].max_hp - damage);
+        assert_eq!(debris[1].hp, debris[1].max_hp - damage);
+    }
+
+    #[test]
+    fn railgun_hits_several_targets_in_a_line() {
+        let mut rng = Rng::new(1);
+        let mut debris: Vec<Debris> = (0..4)
+            .map(|i| debris_at(500.0, 420.0 - i as f32 * 120.0, 0.0))
+            .collect();
+        let player = player_with(&[]);
+        let bullet = Bullet::new_with_player_skills(
+            WeaponType::Railgun,
+            500.0,
+            500.0,
+            0.0,
+            &player,
+            arena(),
+            &mut rng,
+        );
+        let damage = bullet.damage;
+        fly(bullet, &mut debris, 0.3);
+        for d in &debris {
+            assert_eq!(d.hp, d.max_hp - damage);
+        }
+    }
+
+    #[test]
+    fn piercing_shots_hit_the_boss_once() {
+        let mut rng = Rng::new(1);
+        let mut boss = Boss::new(arena(), 1);
+        boss.x = 500.0;
+        boss.y = 400.0;
+        boss.armor = 0.0;
+        let bullet = shot(&player_with(&["PiercingShots:2"]), &mut rng);
+        let damage = bullet.damage;
+
+        let mut bullets = vec![bullet];
+        let hp_before = boss.hp;
+        for _ in 0..30 {
+            for b in bullets.iter_mut() {
+                b.update(1.0 / 120.0, &[], &SpatialGrid::new(128.0));
+            }
+            Bullet::handle_boss_collisions(&mut bullets, &mut boss, &mut EventQueue::new());
+        }
+        assert_eq!(boss.hp, hp_before - damage);
+        assert_eq!(bullets.len(), 1);
+    }
+
+    #[test]
+    fn explosive_payload_damages_neighbours() {
+        let mut rng = Rng::new(1);
+        let damaged = |debris: &[Debris]| debris.iter().filter(|d| d.hp < d.max_hp).count();
+
+        let mut debris = vec![debris_at(500.0, 500.0, 0.0), debris_at(530.0, 500.0, 0.0)];
+        collide(shot(&player_with(&[]), &mut rng), &mut debris);
+        assert_eq!(damaged(&debris), 1);
+
+        let mut debris = vec![debris_at(500.0, 500.0, 0.0), debris_at(530.0, 500.0, 0.0)];
+        collide(
+            shot(&player_with(&["ExplosivePayload:5"]), &mut rng),
+            &mut debris,
+        );
+        assert_eq!(damaged(&debris), 2);
+    }
+
+    #[test]
+    fn explosive_payload_damages_boss() {
+        let mut rng = Rng::new(1);
+        let boss_hp_after = |bullet: Bullet| {
+            let mut boss = Boss::new(arena(), 1);
+            boss.x = 500.0;
+            boss.y = 500.0 - boss.size; // Schuss trifft den Rand
+            Bullet::handle_boss_collisions(&mut vec![bullet], &mut boss, &mut EventQueue::new());
+            boss.hp
+        };
+
+        let base = boss_hp_after(shot(&player_with(&[]), &mut rng));
+        let explosive = boss_hp_after(shot(&player_with(&["ExplosivePayload:5"]), &mut rng));
+        assert!(explosive < base);
+    }
+
+    #[test]
+    fn high_velocity_rounds_penetrate_armor() {
+        let mut rng = Rng::new(1);
+        let mut base_target = vec![debris_at(500.0, 500.0, 0.5)];
+        collide(shot(&player_with(&[]), &mut rng), &mut base_target);
+
+        let mut skilled_target = vec![debris_at(500.0, 500.0, 0.5)];
+        collide(
+            shot(&player_with(&["HighVelocityRounds:5"]), &mut rng),
+            &mut skilled_target,
+        );
+        assert!(skilled_target[0].hp < base_target[0].hp);
+    }
+
+    #[test]
+    fn targeting_system_steers_toward_debris() {
+        let debris = vec![debris_at(700.0, 300.0, 0.0)];
+        let mut grid = SpatialGrid::new(128.0);
+        grid.rebuild(
+            arena(),
+            debris.iter().map(|d| (d.x, d.y, d.collision_radius())),
+        );
+
+        let mut rng = Rng::new(1);
+        let mut base = shot(&player_with(&[]), &mut rng);
+        let mut skilled = shot(&player_with(&["TargetingSystem:5"]), &mut rng);
+        for _ in 0..20 {
+            base.update(1.0 / 120.0, &debris, &grid);
+            skilled.update(1.0 / 120.0, &debris, &grid);
+        }
+        // Ohne Skill fliegt der Schuss geradeaus nach oben, mit Skill dreht er nach rechts
+        assert!((base.x - 500.0).abs() < 1e-3);
+        assert!(skilled.x > base.x + 1.0);
+    }
+
+    #[test]
+    fn critical_strikes_produce_crits() {
+        let mut rng = Rng::new(3);
+        let base = player_with(&[]);
+        let skilled = player_with(&["CriticalStrikes:5"]);
+        let crits = |player: &Player, rng: &mut Rng| {
+            (0..500).filter(|_| shot(player, rng).is_critical).count()
+        };
+        assert_eq!(crits(&base, &mut rng), 0);
+        assert!(crits(&skilled, &mut rng) > 0);
+
+        let crit = (0..500)
+            .map(|_| shot(&skilled, &mut rng))
+            .find(|b| b.is_critical)
+            .unwrap();
+        assert_eq!(crit.damage, shot(&base, &mut rng).damage * CRIT_MULTIPLIER);
+    }
+
+    #[test]
+    fn twin_cannons_fire_extra_shots() {
+        let base = shots_fired(&mut player_with(&[]), 20.0);
+        let skilled = shots_fired(&mut player_with(&["TwinCannons:5"]), 20.0);
+        assert!(skilled > base);
+    }
+
+    #[test]
+    fn rapid_fire_shortens_cooldown() {
+        let base = shots_fired(&mut player_with(&[]), 20.0);
+        let skilled = shots_fired(&mut player_with(&["RapidFire:5"]), 20.0);
+        assert!(skilled > base);
+    }
+
+    #[test]
+    fn overpressure_coolant_trades_fire_rate_for_damage() {
+        let mut rng = Rng::new(1);
+        let base = shot(&player_with(&[]), &mut rng);
+        let skilled = shot(&player_with(&["OverpressureCoolant:5"]), &mut rng);
+        assert!(skilled.damage > base.damage);
+
+        let base_shots = shots_fired(&mut player_with(&[]), 20.0);
+        let skilled_shots = shots_fired(&mut player_with(&["OverpressureCoolant:5"]), 20.0);
+        assert!(skilled_shots < base_shots);
+    }
+
+    // Einen Tick mit der gegebenen Eingabe simulieren, gibt die neuen Schüsse zurück
+    fn tick(player: &mut Player, input: InputState, rng: &mut Rng) -> Vec<Bullet> {
+        let mut bullets = Vec::new();
+        player.update(
+            1.0 / 120.0,
+            &input,
+            arena(),
+            &mut bullets,
+            rng,
+            &mut EventQueue::new(),
+        );
+        bullets
+    }
+
+    fn charging() -> InputState {
+        InputState {
+            charge: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn charged_shot_scales_with_charge() {
+        let player = player_with(&[]);
+        let weak = Bullet::new_charged(&player, 0.0, arena(), &mut Rng::new(1));
+        let full = Bullet::new_charged(&player, 1.0, arena(), &mut Rng::new(1));
+        assert!(full.size > weak.size);
+        assert!(full.damage > weak.damage);
+        assert!(full.remaining_pierces > weak.remaining_pierces);
+        assert!(full.explosion_damage > weak.explosion_damage);
+        assert_eq!(full.style, BulletStyle::Charged);
+    }
+
+    #[test]
+    fn plasma_cannon_makes_a_mega_charged_shot() {
+        let normal = Bullet::new_charged(&player_with(&[]), 1.0, arena(), &mut Rng::new(1));
+        let player = player_with(&["PlasmaCannon:1"]);
+        let plasma = Bullet::new_charged(&player, 1.0, arena(), &mut Rng::new(1));
+        assert!(plasma.size > normal.size);
+        assert!(plasma.damage > normal.damage * 2.0);
+        assert!(plasma.remaining_pierces > normal.remaining_pierces);
+        assert_eq!(plasma.style, BulletStyle::Plasma);
+
+        // Längere Ladezeit bis zum vollen Schuss
+        let mut base = player_with(&[]);
+        let mut skilled = player_with(&["PlasmaCannon:1"]);
+        let mut rng = Rng::new(1);
+        for _ in 0..120 {
+            tick(&mut base, charging(), &mut rng);
+            tick(&mut skilled, charging(), &mut rng);
+        }
+        assert!(skilled.charge_progress() < base.charge_progress());
+    }
+
+    #[test]
+    fn charge_release_never_doubles_up_with_a_normal_shot() {
+        let mut player = player_with(&[]);
+        let mut rng = Rng::new(1);
+        let mut fired = Vec::new();
+        // Feuer durchgehend gehalten, Laden nur am Anfang
+        for i in 0..240 {
+            let input = InputState {
+                shoot: true,
+                charge: i < 60,
+                ..Default::default()
+            };
+            let shots = tick(&mut player, input, &mut rng);
+            assert!(shots.len() <= 1, "two shots in one tick");
+            fired.extend(shots);
+        }
+        let charged = fired
+            .iter()
+            .filter(|b| b.style == BulletStyle::Charged)
+            .count();
+        assert_eq!(charged, 1);
+    }
+
+    #[test]
+    fn charge_release_waits_for_cooldown() {
+        let mut player = player_with(&[]);
+        let mut rng = Rng::new(1);
+        for _ in 0..60 {
+            tick(&mut player, charging(), &mut rng);
+        }
+
+        // Beim Loslassen ist die Kanone noch nicht bereit
+        player.shoot_cooldown = 0.1;
+        let released = InputState::default();
+        assert!(tick(&mut player, released, &mut rng).is_empty());
+
+        let mut fired = Vec::new();
+        for _ in 0..30 {
+            fired.extend(tick(&mut player, released, &mut rng));
+        }
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].style, BulletStyle::Charged);
+        assert!(player.shoot_cooldown > 0.0);
+    }
+
+    #[test]
+    fn beam_cannot_charge() {
+        let mut player = player_with(&[]);
+        player.weapon = WeaponType::Beam;
+        let mut rng = Rng::new(1);
+        for _ in 0..60 {
+            assert!(tick(&mut player, charging(), &mut rng).is_empty());
+        }
+        assert_eq!(player.charge_time, 0.0);
+        assert!(tick(&mut player, InputState::default(), &mut rng).is_empty());
+    }
 }
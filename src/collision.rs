@@ -0,0 +1,175 @@
+use macroquad::prelude::*;
+
+// Punkt in das lokale Koordinatensystem einer gedrehten, skalierten Form umrechnen
+pub fn to_local(point: Vec2, position: Vec2, rotation: f32, scale: f32) -> Vec2 {
+    let (sin_r, cos_r) = rotation.sin_cos();
+    let d = point - position;
+    // Rückwärts drehen (Drehung um -rotation)
+    Vec2::new(d.x * cos_r + d.y * sin_r, -d.x * sin_r + d.y * cos_r) / scale
+}
+
+// Kreis gegen konvexes Polygon nach dem Separating Axis Theorem.
+// Geprüft werden die Kantennormalen und die Achse von der nächsten Ecke zum Kreismittelpunkt.
+pub fn circle_intersects_convex(center: Vec2, radius: f32, polygon: &[Vec2]) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+
+    for i in 0..polygon.len() {
+        let edge = polygon[(i + 1) % polygon.len()] - polygon[i];
+        let axis = Vec2::new(-edge.y, edge.x).normalize_or_zero();
+        if is_separated(axis, center, radius, polygon) {
+            return false;
+        }
+    }
+
+    let closest = polygon
+        .iter()
+        .copied()
+        .min_by(|a, b| {
+            a.distance_squared(center)
+                .total_cmp(&b.distance_squared(center))
+        })
+        .unwrap_or(center);
+    let axis = (center - closest).normalize_or_zero();
+    !is_separated(axis, center, radius, polygon)
+}
+
+fn is_separated(axis: Vec2, center: Vec2, radius: f32, polygon: &[Vec2]) -> bool {
+    if axis == Vec2::ZERO {
+        return false;
+    }
+
+    let (min, max) = polygon
+        .iter()
+        .map(|p| p.dot(axis))
+        .fold((f32::MAX, f32::MIN), |(min, max), d| {
+            (min.min(d), max.max(d))
+        });
+    let c = center.dot(axis);
+    c + radius < min || c - radius > max
+}
+
+// Strahl gegen Kreis: kleinster Parameter t >= 0 mit origin + dir * t auf dem Kreis.
+// Liegt der Ursprung im Kreis, ist t = 0.
+pub fn ray_intersects_circle(origin: Vec2, dir: Vec2, center: Vec2, radius: f32) -> Option<f32> {
+    let to_origin = origin - center;
+    let c = to_origin.length_squared() - radius * radius;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+
+    let a = dir.length_squared();
+    let b = to_origin.dot(dir);
+    let discriminant = b * b - a * c;
+    if a == 0.0 || discriminant < 0.0 {
+        return None;
+    }
+    let t = (-b - discriminant.sqrt()) / a;
+    (t >= 0.0).then_some(t)
+}
+
+// Strahl gegen konvexes Polygon (Cyrus-Beck): Strahl nacheinander an allen Kanten abschneiden.
+// Funktioniert unabhängig von der Umlaufrichtung der Ecken.
+pub fn ray_intersects_convex(origin: Vec2, dir: Vec2, polygon: &[Vec2]) -> Option<f32> {
+    if polygon.len() < 3 {
+        return None;
+    }
+
+    // Umlaufrichtung über die Fläche bestimmen, damit die Normalen nach außen zeigen
+    let area: f32 = (0..polygon.len())
+        .map(|i| polygon[i].perp_dot(polygon[(i + 1) % polygon.len()]))
+        .sum();
+    let orientation = area.signum();
+
+    let mut t_enter = 0.0f32;
+    let mut t_exit = f32::MAX;
+    for i in 0..polygon.len() {
+        let edge = polygon[(i + 1) % polygon.len()] - polygon[i];
+        let normal = Vec2::new(edge.y, -edge.x) * orientation;
+        let num = normal.dot(polygon[i] - origin);
+        let den = normal.dot(dir);
+
+        if den == 0.0 {
+            // Parallel zur Kante und außerhalb → kein Treffer
+            if num < 0.0 {
+                return None;
+            }
+        } else if den > 0.0 {
+            t_exit = t_exit.min(num / den);
+        } else {
+            t_enter = t_enter.max(num / den);
+        }
+
+        if t_enter > t_exit {
+            return None;
+        }
+    }
+    Some(t_enter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Einheitsquadrat von -1 bis 1
+    fn square() -> Vec<Vec2> {
+        vec![
+            Vec2::new(-1.0, -1.0),
+            Vec2::new(1.0, -1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(-1.0, 1.0),
+        ]
+    }
+
+    #[test]
+    fn circle_overlapping_edge_hits() {
+        assert!(circle_intersects_convex(
+            Vec2::new(1.4, 0.0),
+            0.5,
+            &square()
+        ));
+        assert!(!circle_intersects_convex(
+            Vec2::new(1.6, 0.0),
+            0.5,
+            &square()
+        ));
+    }
+
+    #[test]
+    fn circle_near_corner_misses() {
+        // Auf beiden Kantennormalen überlappend, aber nur die Eckachse trennt
+        let center = Vec2::new(1.5, 1.5);
+        assert!(!circle_intersects_convex(center, 0.6, &square()));
+        assert!(circle_intersects_convex(center, 0.8, &square()));
+    }
+
+    #[test]
+    fn circle_center_inside_hits() {
+        assert!(circle_intersects_convex(
+            Vec2::new(0.2, -0.3),
+            0.1,
+            &square()
+        ));
+    }
+
+    #[test]
+    fn rotated_polygon_uses_rotated_edges() {
+        // Um 45° gedreht: Ecken liegen auf den Achsen, die alte Ecke (1, 1) ist frei
+        let rotation = std::f32::consts::FRAC_PI_4;
+        let diamond: Vec<Vec2> = square()
+            .into_iter()
+            .map(|p| Vec2::from_angle(rotation).rotate(p))
+            .collect();
+        assert!(!circle_intersects_convex(
+            Vec2::new(0.95, 0.95),
+            0.2,
+            &diamond
+        ));
+        assert!(circle_intersects_convex(Vec2::new(1.5, 0.0), 0.2, &diamond));
+
+        // to_local dreht den Kreis zurück, damit gilt dasselbe wie für das ungedrehte Quadrat
+        let local = to_local(Vec2::new(0.95, 0.95), Vec2::ZERO, rotation, 1.0);
+        assert!(!circle_intersects_convex(local, 0.2, &square()));
+    }
+}
@@ -1,13 +1,20 @@
-use macroquad::prelude::*;
-use rand::gen_range;
-
-use crate::explosion::Explosion;
-use crate::floating_text::FloatingText;
+use crate::collision::{
+    circle_intersects_convex, ray_intersects_circle, ray_intersects_convex, to_local,
+};
+use crate::debris_kind::{DebrisKind, DebrisShape, pick_debris_kind};
 use crate::help_fn::lerp;
+use crate::player::Player;
+use crate::rng::Rng;
+use crate::world::Arena;
+use macroquad::prelude::*;
 
 pub struct Debris {
+    pub id: u32, // Vergibt World, 0 = noch keine
     pub x: f32,
     pub y: f32,
+    pub prev_x: f32, // Position/Rotation des vorherigen Ticks (für Interpolation)
+    pub prev_y: f32,
+    pub prev_rotation: f32,
     pub size: f32,
     pub speed_multiplier: f32, // NEU: für Slow-Mo Item
     pub velocity_x: f32,
@@ -16,135 +23,192 @@ pub struct Debris {
     pub max_hp: f32,
     pub displayed_hp_progress: f32,
     pub damage: f32,
+    pub armor: f32, // Anteil des Schadens, den die Panzerung schluckt
     pub rotation: f32,
     pub rotation_speed: f32,
-    pub debris_type: u8,
+    pub kind: usize,    // Index in die geladenen DebrisKinds
+    pub generation: u8, // 0 = gespawnt, 1+ = Bruchstück
+    pub shape: DebrisShape,
+    pub tint: [f32; 3],
     pub color_variant: f32,
 }
 
 impl Debris {
-    pub fn new() -> Self {
-        let screen_w = screen_width();
-        let screen_h = screen_height();
-        let screen_size = screen_w.min(screen_h);
+    pub fn new(arena: Arena, kinds: &[DebrisKind], rng: &mut Rng) -> Self {
+        let kind_index = pick_debris_kind(kinds, rng);
+        Self::new_of_kind(arena, kinds, kind_index, rng)
+    }
 
-        let size = gen_range(screen_size * 0.02, screen_size * 0.06);
-        let max_hp = ((size / (screen_size * 0.01)) as f32).max(1.0);
+    // Debris eines bestimmten Archetyps von einer zufälligen Seite
+    pub fn new_of_kind(
+        arena: Arena,
+        kinds: &[DebrisKind],
+        kind_index: usize,
+        rng: &mut Rng,
+    ) -> Self {
+        let screen_w = arena.width;
+        let screen_h = arena.height;
+        let screen_size = arena.min_side();
+        let kind = &kinds[kind_index];
+
+        let size = rng.gen_range(
+            screen_size * kind.size_range.0,
+            screen_size * kind.size_range.1,
+        );
 
         // zufällige Seite wählen: 0=oben, 1=unten, 2=links, 3=rechts
-        let side = gen_range(0, 4);
+        let side = rng.gen_range(0, 4);
 
         // Startposition & Bewegungsrichtung festlegen
         let (x, y, dir_x, dir_y) = match side {
             // oben -> nach unten ins Spielfeld
-            0 => (gen_range(0.0, screen_w), -size, 0.0, 1.0),
+            0 => (rng.gen_range(0.0, screen_w), -size, 0.0, 1.0),
             // unten -> nach oben
-            1 => (gen_range(0.0, screen_w), screen_h + size, 0.0, -1.0),
+            1 => (rng.gen_range(0.0, screen_w), screen_h + size, 0.0, -1.0),
             // links -> nach rechts
-            2 => (-size, gen_range(0.0, screen_h), 1.0, 0.0),
+            2 => (-size, rng.gen_range(0.0, screen_h), 1.0, 0.0),
             // rechts -> nach links
-            _ => (screen_w + size, gen_range(0.0, screen_h), -1.0, 0.0),
+            _ => (screen_w + size, rng.gen_range(0.0, screen_h), -1.0, 0.0),
         };
 
         // kleine Variation in Richtung (damit es nicht perfekt gerade fliegt)
-        let angle_offset = gen_range(-0.3f32, 0.3f32); // ± ca. 17°
-        let base_angle: f32 = (dir_y as f32).atan2(dir_x as f32); // Richtung des Vektors
+        let angle_offset = rng.gen_range(-0.3f32, 0.3f32); // ± ca. 17°
+        let base_angle: f32 = f32::atan2(dir_y, dir_x); // Richtung des Vektors
         let angle = base_angle + angle_offset;
-        let speed = gen_range(screen_size * 0.2, screen_size * 0.6);
-        let velocity_x = angle.cos() * speed;
-        let velocity_y = angle.sin() * speed;
+        let speed = rng.gen_range(
+            screen_size * kind.speed_range.0,
+            screen_size * kind.speed_range.1,
+        );
+        let velocity = Vec2::new(angle.cos() * speed, angle.sin() * speed);
+
+        Self::new_fragment(
+            arena,
+            kind,
+            kind_index,
+            Vec2::new(x, y),
+            velocity,
+            size,
+            rng,
+        )
+    }
+
+    // Debris mit festgelegter Position, Bewegung und Größe (z.B. Bruchstücke)
+    pub fn new_fragment(
+        arena: Arena,
+        kind: &DebrisKind,
+        kind_index: usize,
+        position: Vec2,
+        velocity: Vec2,
+        size: f32,
+        rng: &mut Rng,
+    ) -> Self {
+        let max_hp = (size / (arena.min_side() * 0.01) * kind.hp_scale).max(1.0);
+        let rotation = rng.gen_range(0.0, std::f32::consts::PI * 2.0);
 
         Debris {
-            x,
-            y,
+            id: 0,
+            x: position.x,
+            y: position.y,
+            prev_x: position.x,
+            prev_y: position.y,
+            prev_rotation: rotation,
             size,
             speed_multiplier: 1.0, // Standard: keine Verlangsamung
-            velocity_x,
-            velocity_y,
+            velocity_x: velocity.x,
+            velocity_y: velocity.y,
             hp: max_hp,
             max_hp,
             displayed_hp_progress: 1.0,
-            damage: 1.0,
-            rotation: gen_range(0.0, std::f32::consts::PI * 2.0),
-            rotation_speed: gen_range(-2.0, 2.0),
-            debris_type: gen_range(0, 4),
-            color_variant: gen_range(0.0, 1.0),
+            damage: kind.damage,
+            armor: kind.armor,
+            rotation,
+            rotation_speed: rng.gen_range(-2.0, 2.0),
+            kind: kind_index,
+            generation: 0,
+            shape: kind.shapes[rng.gen_range(0, kind.shapes.len())],
+            tint: kind.tint,
+            color_variant: rng.gen_range(0.0, 1.0),
         }
     }
 
-    pub fn update(
-        &mut self,
-        explosions: &mut Vec<Explosion>,
-        floating_texts: &mut Vec<FloatingText>,
-        score: &mut i32,
-        points_multiplier: f32,
-    ) -> bool {
+    // Gibt true zurück, wenn das Debris zerstört wurde
+    pub fn update(&mut self, dt: f32) -> bool {
+        self.prev_x = self.x;
+        self.prev_y = self.y;
+        self.prev_rotation = self.rotation;
+
         // Bewegung
-        self.x += self.velocity_x * self.speed_multiplier * get_frame_time();
-        self.y += self.velocity_y * self.speed_multiplier * get_frame_time();
+        self.x += self.velocity_x * self.speed_multiplier * dt;
+        self.y += self.velocity_y * self.speed_multiplier * dt;
 
         // Rotation
-        self.rotation += self.rotation_speed * get_frame_time();
+        self.rotation += self.rotation_speed * dt;
 
         // HP-Balken animieren
-        let target_progress = (self.hp.max(0.0) as f32) / (self.max_hp as f32);
+        let target_progress = self.hp.max(0.0) / self.max_hp;
         let lerp_speed = 8.0;
-        self.displayed_hp_progress = lerp(
-            self.displayed_hp_progress,
-            target_progress,
-            get_frame_time() * lerp_speed,
-        );
+        self.displayed_hp_progress =
+            lerp(self.displayed_hp_progress, target_progress, dt * lerp_speed);
 
-        // Prüfen, ob zerstört → wenn ja: Explosion + Score + FloatingText
-        if self.is_destroyed() {
-            let points = (50.0 * points_multiplier).round() as i32;
-            *score += points;
-            floating_texts.push(FloatingText::new(self.x, self.y, points));
-            explosions.push(Explosion::new(
-                self.x,
-                self.y,
-                self.size,
-                self.velocity_x,
-                self.velocity_y,
-                self.debris_type,
-            ));
-            return true; // signalisiert, dass dieses Debris entfernt werden soll
-        }
-
-        false
+        // Prüfen, ob zerstört (Explosion und Score folgen über GameEvent::DebrisDestroyed)
+        self.is_destroyed()
     }
 
-    pub fn draw(&self) {
+    pub fn draw(&self, alpha: f32) {
         let time = get_time() as f32;
+        let pos = Vec2::new(
+            lerp(self.prev_x, self.x, alpha),
+            lerp(self.prev_y, self.y, alpha),
+        );
+        let rotation = lerp(self.prev_rotation, self.rotation, alpha);
 
         // Basis-Farben für Weltraum-Debris (metallisch/grau)
         let base_gray = 0.4 + self.color_variant * 0.3;
         let rust_factor = self.color_variant * 0.2;
 
+        let [tint_r, tint_g, tint_b] = self.tint;
+
         let main_color = Color::new(
-            base_gray + rust_factor,
-            base_gray,
-            base_gray - rust_factor * 0.5,
+            (base_gray + rust_factor) * tint_r,
+            base_gray * tint_g,
+            (base_gray - rust_factor * 0.5) * tint_b,
             1.0,
         );
 
-        let shadow_color = Color::new(base_gray * 0.5, base_gray * 0.5, base_gray * 0.4, 1.0);
+        let shadow_color = Color::new(
+            base_gray * 0.5 * tint_r,
+            base_gray * 0.5 * tint_g,
+            base_gray * 0.4 * tint_b,
+            1.0,
+        );
 
-        // Verschiedene Debris-Formen basierend auf debris_type
-        match self.debris_type {
-            0 => self.draw_angular_debris(main_color, shadow_color),
-            1 => self.draw_rectangular_debris(main_color, shadow_color),
-            2 => self.draw_irregular_debris(main_color, shadow_color),
-            _ => self.draw_complex_debris(main_color, shadow_color),
+        // Verschiedene Debris-Formen je nach Archetyp
+        match self.shape {
+            DebrisShape::Angular => {
+                self.draw_angular_debris(pos, rotation, main_color, shadow_color)
+            }
+            DebrisShape::Rectangular => {
+                self.draw_rectangular_debris(pos, rotation, main_color, shadow_color)
+            }
+            DebrisShape::Irregular => {
+                self.draw_irregular_debris(pos, rotation, main_color, shadow_color)
+            }
+            DebrisShape::Complex => {
+                self.draw_complex_debris(pos, rotation, main_color, shadow_color)
+            }
+        }
+
+        if self.armor > 0.0 {
+            self.draw_armor_plating(pos, rotation);
         }
 
         // Leichtes Glitzern/Funkeln für metallische Oberfläche
-        if (time * 3.0 + self.x * 0.01 + self.y * 0.01).sin() > 0.8 {
-            let sparkle_offset_x = (time * 5.0 + self.rotation).cos() * self.size * 0.3;
-            let sparkle_offset_y = (time * 5.0 + self.rotation).sin() * self.size * 0.3;
+        if (time * 3.0 + pos.x * 0.01 + pos.y * 0.01).sin() > 0.8 {
+            let sparkle_offset_x = (time * 5.0 + rotation).cos() * self.size * 0.3;
+            let sparkle_offset_y = (time * 5.0 + rotation).sin() * self.size * 0.3;
             draw_circle(
-                self.x + sparkle_offset_x,
-                self.y + sparkle_offset_y,
+                pos.x + sparkle_offset_x,
+                pos.y + sparkle_offset_y,
                 self.size * 0.05,
                 WHITE,
             );
@@ -154,8 +218,8 @@ impl Debris {
         if self.hp < self.max_hp || self.displayed_hp_progress < 1.0 {
             let bar_w = self.size * 2.0;
             let bar_h = self.size * 0.15;
-            let bar_x = self.x - bar_w / 2.0;
-            let bar_y = self.y - self.size * 1.4;
+            let bar_x = pos.x - bar_w / 2.0;
+            let bar_y = pos.y - self.size * 1.4;
 
             // Hintergrund (dunkelrot für verlorene HP)
             draw_rectangle(bar_x, bar_y, bar_w, bar_h, Color::new(0.3, 0.1, 0.1, 0.8));
@@ -180,61 +244,82 @@ impl Debris {
         }
     }
 
-    fn draw_angular_debris(&self, main_color: Color, shadow_color: Color) {
+    // Gepanzerte Debris: Plattenring mit Nieten, dicker je mehr Panzerung
+    fn draw_armor_plating(&self, pos: Vec2, rotation: f32) {
+        let plate_color = Color::new(0.75, 0.78, 0.85, 0.9);
+        let thickness = self.size * (0.05 + 0.15 * self.armor);
+        draw_poly_lines(
+            pos.x,
+            pos.y,
+            6,
+            self.size * 0.85,
+            rotation.to_degrees(),
+            thickness,
+            plate_color,
+        );
+
+        // Nieten an den Ecken der Platten
+        for i in 0..6 {
+            let angle = rotation + i as f32 * std::f32::consts::TAU / 6.0;
+            draw_circle(
+                pos.x + angle.cos() * self.size * 0.85,
+                pos.y + angle.sin() * self.size * 0.85,
+                self.size * 0.07,
+                Color::new(0.9, 0.9, 0.95, 1.0),
+            );
+        }
+    }
+
+    fn draw_angular_debris(
+        &self,
+        pos: Vec2,
+        rotation: f32,
+        main_color: Color,
+        shadow_color: Color,
+    ) {
         // Eckiges Trümmerstück
-        let points = [
-            Vec2::new(-0.8, -0.6),
-            Vec2::new(0.9, -0.4),
-            Vec2::new(0.7, 0.8),
-            Vec2::new(-0.5, 0.9),
-            Vec2::new(-0.9, 0.2),
-        ];
+        let points = DebrisShape::Angular.outline();
 
-        self.draw_rotated_polygon(&points, main_color, shadow_color);
+        self.draw_rotated_polygon(pos, rotation, points, main_color, shadow_color);
     }
 
-    fn draw_rectangular_debris(&self, main_color: Color, shadow_color: Color) {
+    fn draw_rectangular_debris(
+        &self,
+        pos: Vec2,
+        rotation: f32,
+        main_color: Color,
+        shadow_color: Color,
+    ) {
         // Rechteckiges Trümmerstück mit Einbuchtungen
-        let points = [
-            Vec2::new(-0.9, -0.7),
-            Vec2::new(0.9, -0.7),
-            Vec2::new(0.9, -0.2),
-            Vec2::new(0.4, -0.2),
-            Vec2::new(0.4, 0.7),
-            Vec2::new(-0.6, 0.7),
-            Vec2::new(-0.6, 0.2),
-            Vec2::new(-0.9, 0.2),
-        ];
+        let points = DebrisShape::Rectangular.outline();
 
-        self.draw_rotated_polygon(&points, main_color, shadow_color);
+        self.draw_rotated_polygon(pos, rotation, points, main_color, shadow_color);
     }
 
-    fn draw_irregular_debris(&self, main_color: Color, shadow_color: Color) {
+    fn draw_irregular_debris(
+        &self,
+        pos: Vec2,
+        rotation: f32,
+        main_color: Color,
+        shadow_color: Color,
+    ) {
         // Unregelmäßiges Trümmerstück
-        let points = [
-            Vec2::new(-0.7, -0.9),
-            Vec2::new(0.3, -0.8),
-            Vec2::new(0.9, -0.3),
-            Vec2::new(0.8, 0.4),
-            Vec2::new(0.2, 0.9),
-            Vec2::new(-0.4, 0.7),
-            Vec2::new(-0.8, 0.1),
-            Vec2::new(-0.9, -0.4),
-        ];
+        let points = DebrisShape::Irregular.outline();
 
-        self.draw_rotated_polygon(&points, main_color, shadow_color);
+        self.draw_rotated_polygon(pos, rotation, points, main_color, shadow_color);
     }
 
-    fn draw_complex_debris(&self, main_color: Color, shadow_color: Color) {
+    fn draw_complex_debris(
+        &self,
+        pos: Vec2,
+        rotation: f32,
+        main_color: Color,
+        shadow_color: Color,
+    ) {
         // Komplexes Trümmerstück mit Löchern
-        let outer_points = [
-            Vec2::new(-0.9, -0.8),
-            Vec2::new(0.8, -0.9),
-            Vec2::new(0.9, 0.7),
-            Vec2::new(-0.7, 0.9),
-        ];
+        let outer_points = DebrisShape::Complex.outline();
 
-        self.draw_rotated_polygon(&outer_points, main_color, shadow_color);
+        self.draw_rotated_polygon(pos, rotation, outer_points, main_color, shadow_color);
 
         // Loch in der Mitte
         let hole_points = [
@@ -244,12 +329,25 @@ impl Debris {
             Vec2::new(-0.3, 0.3),
         ];
 
-        self.draw_rotated_polygon(&hole_points, Color::new(0.0, 0.0, 0.0, 0.0), shadow_color);
+        self.draw_rotated_polygon(
+            pos,
+            rotation,
+            &hole_points,
+            Color::new(0.0, 0.0, 0.0, 0.0),
+            shadow_color,
+        );
     }
 
-    fn draw_rotated_polygon(&self, points: &[Vec2], main_color: Color, shadow_color: Color) {
-        let cos_r = self.rotation.cos();
-        let sin_r = self.rotation.sin();
+    fn draw_rotated_polygon(
+        &self,
+        pos: Vec2,
+        rotation: f32,
+        points: &[Vec2],
+        main_color: Color,
+        shadow_color: Color,
+    ) {
+        let cos_r = rotation.cos();
+        let sin_r = rotation.sin();
 
         // Transformiere und zeichne das Polygon
         let mut transformed_points = Vec::new();
@@ -258,8 +356,8 @@ impl Debris {
             let rotated_y = point.x * sin_r + point.y * cos_r;
 
             transformed_points.push(Vec2::new(
-                self.x + rotated_x * self.size,
-                self.y + rotated_y * self.size,
+                pos.x + rotated_x * self.size,
+                pos.y + rotated_y * self.size,
             ));
         }
 
@@ -289,18 +387,58 @@ impl Debris {
         }
     }
 
-    pub fn is_off_screen(&self) -> bool {
+    pub fn is_off_screen(&self, arena: Arena) -> bool {
         let margin = self.size * 2.0;
         self.x < -margin
-            || self.x > screen_width() + margin
+            || self.x > arena.width + margin
             || self.y < -margin
-            || self.y > screen_height() + margin
+            || self.y > arena.height + margin
+    }
+
+    pub fn collides_with(&self, player: &Player) -> bool {
+        self.hits_circle(player.x, player.y, player.size)
+    }
+
+    // Radius um die ganze Form, für Raster und Broadphase
+    pub fn collision_radius(&self) -> f32 {
+        self.size * self.shape.bounding_radius()
+    }
+
+    // Kreis gegen die gedrehte Form: erst grob per Umkreis, dann genau per SAT
+    pub fn hits_circle(&self, x: f32, y: f32, radius: f32) -> bool {
+        let dx = self.x - x;
+        let dy = self.y - y;
+        let reach = self.collision_radius() + radius;
+        if dx * dx + dy * dy >= reach * reach {
+            return false;
+        }
+
+        let center = to_local(
+            Vec2::new(x, y),
+            Vec2::new(self.x, self.y),
+            self.rotation,
+            self.size,
+        );
+        let local_radius = radius / self.size;
+        self.shape
+            .collision_parts()
+            .iter()
+            .any(|part| circle_intersects_convex(center, local_radius, part))
     }
 
-    pub fn collides_with(&self, player: &crate::player::Player) -> bool {
-        let dx = self.x - player.x;
-        let dy = self.y - player.y;
-        (dx * dx + dy * dy).sqrt() < self.size + player.size
+    // Strahl gegen die gedrehte Form, gibt den Abstand entlang `dir` (normiert) zurück
+    pub fn ray_hit(&self, origin: Vec2, dir: Vec2) -> Option<f32> {
+        let position = Vec2::new(self.x, self.y);
+        ray_intersects_circle(origin, dir, position, self.collision_radius())?;
+
+        // Affine Abbildung: t bleibt im lokalen Raum gleich
+        let local_origin = to_local(origin, position, self.rotation, self.size);
+        let local_dir = to_local(origin + dir, position, self.rotation, self.size) - local_origin;
+        self.shape
+            .collision_parts()
+            .iter()
+            .filter_map(|part| ray_intersects_convex(local_origin, local_dir, part))
+            .min_by(|a, b| a.total_cmp(b))
     }
 
     pub fn take_damage(&mut self, damage: f32) -> bool {
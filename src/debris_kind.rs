@@ -0,0 +1,236 @@
+use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+use crate::rng::Rng;
+
+pub const DEBRIS_KINDS_FILE: &str = "assets/debris_kinds.json";
+
+// Form, mit der ein Debris gezeichnet wird
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebrisShape {
+    Angular,
+    Rectangular,
+    Irregular,
+    Complex,
+}
+
+// Umrisse in Einheiten von `size`, so wie sie gezeichnet werden
+const ANGULAR_OUTLINE: [Vec2; 5] = [
+    Vec2::new(-0.8, -0.6),
+    Vec2::new(0.9, -0.4),
+    Vec2::new(0.7, 0.8),
+    Vec2::new(-0.5, 0.9),
+    Vec2::new(-0.9, 0.2),
+];
+
+// Rechteckiges Trümmerstück mit Einbuchtungen (nicht konvex)
+const RECTANGULAR_OUTLINE: [Vec2; 8] = [
+    Vec2::new(-0.9, -0.7),
+    Vec2::new(0.9, -0.7),
+    Vec2::new(0.9, -0.2),
+    Vec2::new(0.4, -0.2),
+    Vec2::new(0.4, 0.7),
+    Vec2::new(-0.6, 0.7),
+    Vec2::new(-0.6, 0.2),
+    Vec2::new(-0.9, 0.2),
+];
+
+const IRREGULAR_OUTLINE: [Vec2; 8] = [
+    Vec2::new(-0.7, -0.9),
+    Vec2::new(0.3, -0.8),
+    Vec2::new(0.9, -0.3),
+    Vec2::new(0.8, 0.4),
+    Vec2::new(0.2, 0.9),
+    Vec2::new(-0.4, 0.7),
+    Vec2::new(-0.8, 0.1),
+    Vec2::new(-0.9, -0.4),
+];
+
+const COMPLEX_OUTLINE: [Vec2; 4] = [
+    Vec2::new(-0.9, -0.8),
+    Vec2::new(0.8, -0.9),
+    Vec2::new(0.9, 0.7),
+    Vec2::new(-0.7, 0.9),
+];
+
+// Das Rechteck in konvexe Teile zerlegt, damit SAT funktioniert
+const RECTANGULAR_PARTS: [&[Vec2]; 3] = [
+    &[
+        Vec2::new(-0.9, -0.7),
+        Vec2::new(0.9, -0.7),
+        Vec2::new(0.9, -0.2),
+        Vec2::new(-0.9, -0.2),
+    ],
+    &[
+        Vec2::new(-0.9, -0.2),
+        Vec2::new(0.4, -0.2),
+        Vec2::new(0.4, 0.2),
+        Vec2::new(-0.9, 0.2),
+    ],
+    &[
+        Vec2::new(-0.6, 0.2),
+        Vec2::new(0.4, 0.2),
+        Vec2::new(0.4, 0.7),
+        Vec2::new(-0.6, 0.7),
+    ],
+];
+
+impl DebrisShape {
+    // Gezeichneter Umriss
+    pub fn outline(&self) -> &'static [Vec2] {
+        match self {
+            DebrisShape::Angular => &ANGULAR_OUTLINE,
+            DebrisShape::Rectangular => &RECTANGULAR_OUTLINE,
+            DebrisShape::Irregular => &IRREGULAR_OUTLINE,
+            DebrisShape::Complex => &COMPLEX_OUTLINE,
+        }
+    }
+
+    // Kollisionsform als konvexe Teilpolygone. Das Loch im Complex-Debris
+    // wird nur angedeutet, daher zählt der volle Umriss.
+    pub fn collision_parts(&self) -> &'static [&'static [Vec2]] {
+        match self {
+            DebrisShape::Angular => &[&ANGULAR_OUTLINE],
+            DebrisShape::Rectangular => &RECTANGULAR_PARTS,
+            DebrisShape::Irregular => &[&IRREGULAR_OUTLINE],
+            DebrisShape::Complex => &[&COMPLEX_OUTLINE],
+        }
+    }
+
+    // Radius eines Kreises um den ganzen Umriss (für die Broadphase)
+    pub fn bounding_radius(&self) -> f32 {
+        self.outline()
+            .iter()
+            .map(|p| p.length())
+            .fold(0.0, f32::max)
+    }
+}
+
+// Archetyp eines Debris. Größen und Geschwindigkeiten sind Anteile der kürzeren
+// Arena-Seite, damit die Werte unabhängig von der Arena-Größe bleiben.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DebrisKind {
+    pub name: String,
+    pub spawn_weight: f32,       // Relative Häufigkeit beim Spawnen
+    pub size_range: (f32, f32),  // Radius
+    pub speed_range: (f32, f32), // pro Sekunde
+    pub hp_scale: f32,           // HP = Größe in Prozent der Arena * hp_scale
+    #[serde(default)]
+    pub armor: f32, // Anteil des Schadens, den die Panzerung schluckt (0..1)
+    pub damage: f32,             // Schaden am Spieler bei Kollision
+    pub score: i32,              // Punkte beim Zerstören (vor Multiplikator)
+    pub escape_score: i32,       // Punkte, wenn es das Spielfeld verlässt
+    pub shapes: Vec<DebrisShape>,
+    pub tint: [f32; 3],                   // Multiplikator auf die Metallfarbe
+    pub explosion_palette: Vec<[f32; 3]>, // Partikelfarben der Explosion
+    // Ab dieser Größe zerbricht das Debris beim Zerstören, kleinere vergehen einfach
+    #[serde(default = "default_split_min_size")]
+    pub split_min_size: f32,
+    #[serde(default = "default_fragment_count")]
+    pub fragment_count: (u8, u8), // min/max Anzahl der Bruchstücke
+}
+
+fn default_split_min_size() -> f32 {
+    0.04
+}
+
+fn default_fragment_count() -> (u8, u8) {
+    (2, 3)
+}
+
+impl DebrisKind {
+    // Standard-Schrott, entspricht dem ursprünglichen Debris
+    pub fn scrap() -> Self {
+        Self {
+            name: "scrap".to_string(),
+            spawn_weight: 1.0,
+            size_range: (0.02, 0.06),
+            speed_range: (0.2, 0.6),
+            hp_scale: 1.0,
+            armor: 0.0,
+            damage: 1.0,
+            score: 50,
+            escape_score: 10,
+            shapes: vec![
+                DebrisShape::Angular,
+                DebrisShape::Rectangular,
+                DebrisShape::Irregular,
+                DebrisShape::Complex,
+            ],
+            tint: [1.0, 1.0, 1.0],
+            explosion_palette: vec![
+                [1.0, 0.6, 0.2], // Orange
+                [1.0, 0.8, 0.3], // Gelb-Orange
+                [0.9, 0.4, 0.1], // Rot-Orange
+                [1.0, 0.7, 0.4], // Helles Orange
+            ],
+            split_min_size: default_split_min_size(),
+            fragment_count: default_fragment_count(),
+        }
+    }
+
+    pub fn explosion_colors(&self) -> Vec<Color> {
+        self.explosion_palette
+            .iter()
+            .map(|[r, g, b]| Color::new(*r, *g, *b, 1.0))
+            .collect()
+    }
+
+    // Ungültige Werte aus der Config abfangen
+    fn is_valid(&self) -> bool {
+        self.spawn_weight > 0.0
+            && self.size_range.0 > 0.0
+            && self.size_range.0 <= self.size_range.1
+            && self.speed_range.0 <= self.speed_range.1
+            && self.hp_scale > 0.0
+            && (0.0..1.0).contains(&self.armor)
+            && !self.shapes.is_empty()
+            && !self.explosion_palette.is_empty()
+            && self.split_min_size > 0.0 // Sonst zerbrechen Bruchstücke endlos weiter
+            && self.fragment_count.0 <= self.fragment_count.1
+    }
+}
+
+// Archetypen aus der Config laden, bei Fehlern die eingebauten Standardwerte
+pub fn load_debris_kinds() -> Vec<DebrisKind> {
+    fs::read_to_string(DEBRIS_KINDS_FILE)
+        .ok()
+        .and_then(|contents| serde_json::from_str::<Vec<DebrisKind>>(&contents).ok())
+        .map(|kinds| {
+            kinds
+                .into_iter()
+                .filter(|k| k.is_valid())
+                .collect::<Vec<_>>()
+        })
+        .filter(|kinds| !kinds.is_empty())
+        .unwrap_or_else(|| vec![DebrisKind::scrap()])
+}
+
+// Zufälligen Archetyp nach spawn_weight wählen, gibt den Index zurück
+pub fn pick_debris_kind(kinds: &[DebrisKind], rng: &mut Rng) -> usize {
+    let total: f32 = kinds.iter().map(|k| k.spawn_weight).sum();
+    let mut roll = rng.gen_range(0.0, total);
+    for (i, kind) in kinds.iter().enumerate() {
+        if roll < kind.spawn_weight {
+            return i;
+        }
+        roll -= kind.spawn_weight;
+    }
+    kinds.len() - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_min_size_must_be_positive() {
+        let mut kind = DebrisKind::scrap();
+        assert!(kind.is_valid());
+        kind.split_min_size = 0.0;
+        assert!(!kind.is_valid());
+        kind.split_min_size = -0.1;
+        assert!(!kind.is_valid());
+    }
+}
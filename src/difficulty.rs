@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+// Schwierigkeitsstufe, wird vor dem Run gewählt und in den Settings gespeichert
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Nightmare,
+}
+
+// Konkrete Werte einer Stufe. Alles relativ zu Normal (1.0 = unverändert).
+pub struct DifficultyPreset {
+    pub spawn_rate_scale: f32, // Abstand zwischen Spawns (kleiner = mehr Debris)
+    pub decay_strength: f32,   // Exponent auf das Beschleunigen pro Durchlauf (0.9 → 0.9^x)
+    pub min_spawn_rate_scale: f32,
+    pub debris_speed_scale: f32,
+    pub debris_hp_scale: f32,
+    pub player_max_hp: f32,
+    pub item_spawn_interval: f32, // Sekunden zwischen zufälligen Items
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Normal,
+        Difficulty::Hard,
+        Difficulty::Nightmare,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+            Difficulty::Nightmare => "Nightmare",
+        }
+    }
+
+    pub fn preset(&self) -> DifficultyPreset {
+        match self {
+            Difficulty::Easy => DifficultyPreset {
+                spawn_rate_scale: 1.3,
+                decay_strength: 0.5,
+                min_spawn_rate_scale: 1.5,
+                debris_speed_scale: 0.8,
+                debris_hp_scale: 0.75,
+                player_max_hp: 5.0,
+                item_spawn_interval: 4.0,
+            },
+            Difficulty::Normal => DifficultyPreset {
+                spawn_rate_scale: 1.0,
+                decay_strength: 1.0,
+                min_spawn_rate_scale: 1.0,
+                debris_speed_scale: 1.0,
+                debris_hp_scale: 1.0,
+                player_max_hp: 3.0,
+                item_spawn_interval: 5.0,
+            },
+            Difficulty::Hard => DifficultyPreset {
+                spawn_rate_scale: 0.8,
+                decay_strength: 1.5,
+                min_spawn_rate_scale: 0.75,
+                debris_speed_scale: 1.2,
+                debris_hp_scale: 1.3,
+                player_max_hp: 3.0,
+                item_spawn_interval: 6.0,
+            },
+            Difficulty::Nightmare => DifficultyPreset {
+                spawn_rate_scale: 0.6,
+                decay_strength: 2.0,
+                min_spawn_rate_scale: 0.5,
+                debris_speed_scale: 1.4,
+                debris_hp_scale: 1.6,
+                player_max_hp: 2.0,
+                item_spawn_interval: 8.0,
+            },
+        }
+    }
+
+    // Nächste/vorherige Stufe für die Auswahl im Menü
+    pub fn cycle(&self, step: i32) -> Difficulty {
+        let len = Self::ALL.len() as i32;
+        let index = Self::ALL.iter().position(|d| d == self).unwrap_or(1) as i32;
+        Self::ALL[(index + step).rem_euclid(len) as usize]
+    }
+}
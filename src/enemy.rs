@@ -0,0 +1,241 @@
+use macroquad::prelude::*;
+
+use crate::bullet::Bullet;
+use crate::help_fn::lerp;
+use crate::rng::Rng;
+use crate::world::Arena;
+
+pub const GUNSHIP_SCORE: i32 = 300;
+const GUNSHIP_HP: f32 = 12.0;
+const GUNSHIP_ARMOR: f32 = 0.1;
+const FIRE_INTERVAL: f32 = 1.6; // Sekunden zwischen zwei Schüssen (plus Zufall)
+const TURN_SPEED: f32 = 3.0; // Radiant pro Sekunde
+const AIM_TOLERANCE: f32 = 0.2; // Erst schießen, wenn ungefähr auf den Spieler gezielt
+
+// Feindliches Kanonenboot: fliegt von oben ein, sucht sich eine Position,
+// zielt auf den Spieler und feuert. Wechselt ab und zu die Position.
+pub struct Gunship {
+    pub id: u32, // Vergibt World, 0 = noch keine
+    pub x: f32,
+    pub y: f32,
+    pub prev_x: f32, // Position des vorherigen Ticks (für Interpolation)
+    pub prev_y: f32,
+    pub target_x: f32, // Position, die angeflogen wird
+    pub target_y: f32,
+    pub size: f32,
+    pub speed: f32,
+    pub rotation: f32, // 0 = Nase nach oben, wie beim Spieler
+    pub prev_rotation: f32,
+    pub hp: f32,
+    pub max_hp: f32,
+    pub armor: f32,
+    pub displayed_hp_progress: f32,
+    pub fire_cooldown: f32,
+    pub reposition_timer: f32,
+    pub time_scale: f32, // SlowMotion/TimeFreeze, wie speed_multiplier bei Debris
+}
+
+impl Gunship {
+    pub fn new(arena: Arena, rng: &mut Rng) -> Self {
+        let size = arena.min_side() * 0.035;
+        let x = rng.gen_range(arena.width * 0.1, arena.width * 0.9);
+        let y = -size * 2.0; // Startet oberhalb des Spielfelds
+        let target = Self::pick_position(arena, rng);
+        let rotation = std::f32::consts::PI; // Nase nach unten
+
+        Gunship {
+            id: 0,
+            x,
+            y,
+            prev_x: x,
+            prev_y: y,
+            target_x: target.x,
+            target_y: target.y,
+            size,
+            speed: arena.min_side() * 0.25,
+            rotation,
+            prev_rotation: rotation,
+            hp: GUNSHIP_HP,
+            max_hp: GUNSHIP_HP,
+            armor: GUNSHIP_ARMOR,
+            displayed_hp_progress: 1.0,
+            fire_cooldown: FIRE_INTERVAL,
+            reposition_timer: rng.gen_range(4.0, 7.0),
+            time_scale: 1.0,
+        }
+    }
+
+    // Zufällige Position im oberen Teil des Spielfelds
+    fn pick_position(arena: Arena, rng: &mut Rng) -> Vec2 {
+        Vec2::new(
+            rng.gen_range(arena.width * 0.1, arena.width * 0.9),
+            rng.gen_range(arena.height * 0.08, arena.height * 0.4),
+        )
+    }
+
+    // Gibt true zurück, wenn das Schiff zerstört ist
+    pub fn update(
+        &mut self,
+        dt: f32,
+        player_pos: Vec2,
+        arena: Arena,
+        bullets: &mut Vec<Bullet>,
+        rng: &mut Rng,
+    ) -> bool {
+        self.prev_x = self.x;
+        self.prev_y = self.y;
+        self.prev_rotation = self.rotation;
+        let dt_scaled = dt * self.time_scale;
+
+        // Zur Zielposition fliegen
+        let to_target = Vec2::new(self.target_x - self.x, self.target_y - self.y);
+        let distance = to_target.length();
+        let step = (self.speed * dt_scaled).min(distance);
+        if distance > 0.0 {
+            self.x += to_target.x / distance * step;
+            self.y += to_target.y / distance * step;
+        }
+        let in_position = distance < self.size * 0.5;
+
+        // Langsam zum Spieler drehen
+        let desired =
+            (player_pos.y - self.y).atan2(player_pos.x - self.x) + std::f32::consts::FRAC_PI_2;
+        let mut diff = desired - self.rotation;
+        while diff > std::f32::consts::PI {
+            diff -= std::f32::consts::TAU;
+        }
+        while diff < -std::f32::consts::PI {
+            diff += std::f32::consts::TAU;
+        }
+        let max_turn = TURN_SPEED * dt_scaled;
+        self.rotation += diff.clamp(-max_turn, max_turn);
+
+        if in_position {
+            // Feuern, sobald ausgerichtet
+            self.fire_cooldown -= dt_scaled;
+            if self.fire_cooldown <= 0.0 && diff.abs() < AIM_TOLERANCE {
+                let nose = self.rotation - std::f32::consts::FRAC_PI_2;
+                bullets.push(Bullet::new_enemy(
+                    self.x + nose.cos() * self.size,
+                    self.y + nose.sin() * self.size,
+                    self.rotation + rng.gen_range(-0.05, 0.05),
+                    arena,
+                ));
+                self.fire_cooldown = FIRE_INTERVAL + rng.gen_range(0.0, 0.6);
+            }
+
+            // Nach einer Weile neue Position suchen
+            self.reposition_timer -= dt_scaled;
+            if self.reposition_timer <= 0.0 {
+                let target = Self::pick_position(arena, rng);
+                self.target_x = target.x;
+                self.target_y = target.y;
+                self.reposition_timer = rng.gen_range(4.0, 7.0);
+            }
+        }
+
+        // HP-Balken animieren
+        let target_progress = self.hp.max(0.0) / self.max_hp;
+        self.displayed_hp_progress = lerp(self.displayed_hp_progress, target_progress, dt * 8.0);
+
+        self.is_destroyed()
+    }
+
+    pub fn draw(&self, alpha: f32) {
+        let pos = Vec2::new(
+            lerp(self.prev_x, self.x, alpha),
+            lerp(self.prev_y, self.y, alpha),
+        );
+        let rotation = lerp(self.prev_rotation, self.rotation, alpha);
+        let (sin_a, cos_a) = rotation.sin_cos();
+        let rotate = |p: Vec2| {
+            pos + Vec2::new(p.x * cos_a - p.y * sin_a, p.x * sin_a + p.y * cos_a) * self.size
+        };
+
+        let hull_color = Color::new(0.55, 0.15, 0.15, 1.0);
+        let wing_color = Color::new(0.35, 0.1, 0.12, 1.0);
+
+        // Flügel
+        draw_triangle(
+            rotate(Vec2::new(-1.1, 0.6)),
+            rotate(Vec2::new(0.0, -0.2)),
+            rotate(Vec2::new(-0.3, 0.7)),
+            wing_color,
+        );
+        draw_triangle(
+            rotate(Vec2::new(1.1, 0.6)),
+            rotate(Vec2::new(0.0, -0.2)),
+            rotate(Vec2::new(0.3, 0.7)),
+            wing_color,
+        );
+
+        // Rumpf
+        draw_triangle(
+            rotate(Vec2::new(0.0, -1.0)),
+            rotate(Vec2::new(-0.45, 0.8)),
+            rotate(Vec2::new(0.45, 0.8)),
+            hull_color,
+        );
+
+        // Kanzel, leuchtet kurz vor dem Schuss auf
+        let charge = 1.0 - (self.fire_cooldown / FIRE_INTERVAL).clamp(0.0, 1.0);
+        let cockpit = rotate(Vec2::new(0.0, -0.1));
+        draw_circle(
+            cockpit.x,
+            cockpit.y,
+            self.size * 0.2,
+            Color::new(1.0, 0.3 + 0.5 * charge, 0.2, 1.0),
+        );
+
+        // Triebwerk
+        let engine = rotate(Vec2::new(0.0, 0.9));
+        draw_circle(
+            engine.x,
+            engine.y,
+            self.size * 0.15,
+            Color::new(1.0, 0.6, 0.2, 0.8),
+        );
+
+        // HP-Balken
+        if self.hp < self.max_hp || self.displayed_hp_progress < 1.0 {
+            let bar_w = self.size * 2.0;
+            let bar_h = self.size * 0.15;
+            let bar_x = pos.x - bar_w / 2.0;
+            let bar_y = pos.y - self.size * 1.4;
+
+            draw_rectangle(bar_x, bar_y, bar_w, bar_h, Color::new(0.3, 0.1, 0.1, 0.8));
+            draw_rectangle(
+                bar_x,
+                bar_y,
+                bar_w * self.displayed_hp_progress,
+                bar_h,
+                Color::new(0.9, 0.3, 0.2, 0.9),
+            );
+            draw_rectangle_lines(
+                bar_x,
+                bar_y,
+                bar_w,
+                bar_h,
+                1.0,
+                Color::new(0.8, 0.8, 0.8, 0.6),
+            );
+        }
+    }
+
+    pub fn explosion_colors() -> [Color; 3] {
+        [
+            Color::new(1.0, 0.3, 0.2, 1.0),
+            Color::new(1.0, 0.6, 0.2, 1.0),
+            Color::new(0.6, 0.6, 0.6, 1.0),
+        ]
+    }
+
+    pub fn take_damage(&mut self, damage: f32) -> bool {
+        self.hp -= damage;
+        self.hp <= 0.0
+    }
+
+    pub fn is_destroyed(&self) -> bool {
+        self.hp <= 0.0
+    }
+}
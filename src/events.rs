@@ -0,0 +1,121 @@
+use crate::items::ItemType;
+use crate::weapon::WeaponType;
+
+// Alles, was während eines Ticks im Spiel passiert. Systeme melden Ereignisse nur,
+// die Reaktionen (Score, Effekte, Statistik, Skills) hängen sich in World an.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    DebrisDestroyed {
+        x: f32,
+        y: f32,
+        size: f32,
+        velocity_x: f32,
+        velocity_y: f32,
+        kind: usize,
+        generation: u8,
+    },
+    DebrisEscaped {
+        x: f32,
+        y: f32,
+        kind: usize,
+    },
+    GunshipDestroyed {
+        x: f32,
+        y: f32,
+        size: f32,
+    },
+    BossDefeated {
+        x: f32,
+        y: f32,
+        size: f32,
+        number: u32,
+    },
+    BulletHit {
+        x: f32,
+        y: f32,
+        damage: f32,
+        is_critical: bool,
+        deflected: bool, // Panzerung hat den Großteil geschluckt
+    },
+    PlayerDamaged {
+        damage: f32,
+    },
+    ItemPickedUp {
+        item_type: ItemType,
+        x: f32,
+        y: f32,
+    },
+    WeaponPickedUp {
+        weapon: WeaponType,
+        x: f32,
+        y: f32,
+    },
+    EffectExpired {
+        effect_type: ItemType,
+    },
+    WaveStarted {
+        number: u32,
+    },
+    RunEnded {
+        score: i32,
+    },
+}
+
+// Warteschlange für die Ereignisse eines Ticks
+pub struct EventQueue {
+    events: Vec<GameEvent>,
+}
+
+impl EventQueue {
+    pub fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    pub fn push(&mut self, event: GameEvent) {
+        self.events.push(event);
+    }
+
+    // Alle gesammelten Ereignisse in Reihenfolge herausnehmen
+    pub fn drain(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+// Statistik eines Runs, gefüttert aus den Ereignissen
+#[derive(Debug, Clone, Default)]
+pub struct RunStats {
+    pub debris_destroyed: u32,
+    pub debris_escaped: u32,
+    pub gunships_destroyed: u32,
+    pub bosses_defeated: u32,
+    pub bullet_hits: u32,
+    pub critical_hits: u32,
+    pub damage_taken: f32,
+    pub items_picked_up: u32,
+    pub weapons_picked_up: u32,
+    pub waves_reached: u32,
+    pub final_score: i32,
+}
+
+impl RunStats {
+    pub fn record(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::DebrisDestroyed { .. } => self.debris_destroyed += 1,
+            GameEvent::DebrisEscaped { .. } => self.debris_escaped += 1,
+            GameEvent::GunshipDestroyed { .. } => self.gunships_destroyed += 1,
+            GameEvent::BossDefeated { .. } => self.bosses_defeated += 1,
+            GameEvent::BulletHit { is_critical, .. } => {
+                self.bullet_hits += 1;
+                if *is_critical {
+                    self.critical_hits += 1;
+                }
+            }
+            GameEvent::PlayerDamaged { damage } => self.damage_taken += damage,
+            GameEvent::ItemPickedUp { .. } => self.items_picked_up += 1,
+            GameEvent::WeaponPickedUp { .. } => self.weapons_picked_up += 1,
+            GameEvent::EffectExpired { .. } => {}
+            GameEvent::WaveStarted { number } => self.waves_reached = *number,
+            GameEvent::RunEnded { score } => self.final_score = *score,
+        }
+    }
+}
@@ -1,9 +1,12 @@
+use crate::help_fn::lerp;
+use crate::rng::Rng;
 use macroquad::prelude::*;
-use rand::gen_range;
 
 pub struct ExplosionParticle {
     pub x: f32,
     pub y: f32,
+    pub prev_x: f32, // Position des vorherigen Ticks (für Interpolation)
+    pub prev_y: f32,
     pub velocity_x: f32,
     pub velocity_y: f32,
     pub size: f32,
@@ -25,6 +28,8 @@ impl ExplosionParticle {
         ExplosionParticle {
             x,
             y,
+            prev_x: x,
+            prev_y: y,
             velocity_x,
             velocity_y,
             size,
@@ -34,19 +39,24 @@ impl ExplosionParticle {
         }
     }
 
-    pub fn update(&mut self) {
-        self.x += self.velocity_x * get_frame_time();
-        self.y += self.velocity_y * get_frame_time();
+    pub fn update(&mut self, dt: f32) {
+        self.prev_x = self.x;
+        self.prev_y = self.y;
+        self.x += self.velocity_x * dt;
+        self.y += self.velocity_y * dt;
 
-        // Verlangsamung durch "Reibung"
-        self.velocity_x *= 0.98;
-        self.velocity_y *= 0.98;
+        // Verlangsamung durch "Reibung" (0.98 pro 1/60 s, unabhängig von der Tickrate)
+        let friction = 0.98f32.powf(dt * 60.0);
+        self.velocity_x *= friction;
+        self.velocity_y *= friction;
 
         // Lebensdauer verringern
-        self.life -= get_frame_time();
+        self.life -= dt;
     }
 
-    pub fn draw(&self) {
+    pub fn draw(&self, interpolation: f32) {
+        let x = lerp(self.prev_x, self.x, interpolation);
+        let y = lerp(self.prev_y, self.y, interpolation);
         let alpha = (self.life / self.max_life).max(0.0);
         let mut color = self.color;
         color.a = alpha;
@@ -54,12 +64,12 @@ impl ExplosionParticle {
         // Partikel wird kleiner mit der Zeit
         let current_size = self.size * alpha;
 
-        draw_circle(self.x, self.y, current_size, color);
+        draw_circle(x, y, current_size, color);
 
         // Zusätzlicher Glow-Effekt
         if alpha > 0.5 {
             let glow_color = Color::new(color.r, color.g, color.b, alpha * 0.3);
-            draw_circle(self.x, self.y, current_size * 2.0, glow_color);
+            draw_circle(x, y, current_size * 2.0, glow_color);
         }
     }
 
@@ -79,43 +89,44 @@ impl Explosion {
         size: f32,
         velocity_x: f32,
         velocity_y: f32,
-        debris_type: u8,
+        palette: &[Color],
+        rng: &mut Rng,
     ) -> Self {
         let mut particles = Vec::new();
         let particle_count = (size * 0.5) as usize + 5; // Größere Debris = mehr Partikel
 
         for _ in 0..particle_count {
-            let angle = gen_range(0.0, std::f32::consts::PI * 2.0);
-            let speed = gen_range(size * 2.0, size * 8.0);
+            let angle = rng.gen_range(0.0, std::f32::consts::PI * 2.0);
+            let speed = rng.gen_range(size * 2.0, size * 8.0);
             let particle_velocity_x = angle.cos() * speed + velocity_x * 0.3; // Erbt etwas von der ursprünglichen Bewegung
             let particle_velocity_y = angle.sin() * speed + velocity_y * 0.3;
 
             particles.push(ExplosionParticle::new(
-                x + gen_range(-size * 0.2, size * 0.2),
-                y + gen_range(-size * 0.2, size * 0.2),
+                x + rng.gen_range(-size * 0.2, size * 0.2),
+                y + rng.gen_range(-size * 0.2, size * 0.2),
                 particle_velocity_x,
                 particle_velocity_y,
-                gen_range(size * 0.05, size * 0.15),
-                gen_range(0.5, 1.5),
-                get_explosion_color(debris_type),
+                rng.gen_range(size * 0.05, size * 0.15),
+                rng.gen_range(0.5, 1.5),
+                palette[rng.gen_range(0, palette.len())],
             ));
         }
 
         Explosion { particles }
     }
 
-    pub fn update(&mut self) {
+    pub fn update(&mut self, dt: f32) {
         for particle in &mut self.particles {
-            particle.update();
+            particle.update(dt);
         }
 
         // Entferne tote Partikel
         self.particles.retain(|p| p.is_alive());
     }
 
-    pub fn draw(&self) {
+    pub fn draw(&self, alpha: f32) {
         for particle in &self.particles {
-            particle.draw();
+            particle.draw(alpha);
         }
     }
 
@@ -123,13 +134,3 @@ impl Explosion {
         self.particles.is_empty()
     }
 }
-
-fn get_explosion_color(debris_type: u8) -> Color {
-    // Verschiedene Explosionsfarben basierend auf debris_type
-    match debris_type {
-        0 => Color::new(1.0, 0.6, 0.2, 1.0), // Orange
-        1 => Color::new(1.0, 0.8, 0.3, 1.0), // Gelb-Orange
-        2 => Color::new(0.9, 0.4, 0.1, 1.0), // Rot-Orange
-        _ => Color::new(1.0, 0.7, 0.4, 1.0), // Helles Orange
-    }
-}
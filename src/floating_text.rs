@@ -38,9 +38,9 @@ impl FloatingText {
         }
     }
 
-    pub fn update(&mut self) {
-        self.y += self.velocity_y * get_frame_time();
-        self.lifetime -= get_frame_time();
+    pub fn update(&mut self, dt: f32) {
+        self.y += self.velocity_y * dt;
+        self.lifetime -= dt;
 
         // Verblassen über Zeit
         let alpha = (self.lifetime / self.max_lifetime).clamp(0.0, 1.0);
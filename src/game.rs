@@ -0,0 +1,999 @@
+use macroquad::prelude::*;
+use std::collections::BTreeMap;
+
+use crate::adaptive::AdaptiveDirector;
+use crate::boss::{BOSS_SEGMENTS, Boss};
+use crate::difficulty::Difficulty;
+use crate::fps::FpsCounter;
+use crate::input::{ControlScheme, InputState};
+use crate::items::ItemType;
+use crate::music_manager::MusicManager;
+use crate::replay::{
+    BEST_RUN_FILE, LAST_RUN_FILE, Replay, ReplayPlayback, ReplayRecorder, asset_hash, load_replay,
+    save_replay,
+};
+use crate::rng::{format_seed_code, random_seed};
+use crate::savegame::{add_bosses_defeated, load_save, update_highscore};
+use crate::settings::{SettingsUI, draw_button};
+use crate::skill_tree::SkillTreeManager;
+use crate::weapon::WeaponType;
+use crate::world::{Arena, FIXED_DT, World};
+
+// Maximal nachzuholende Zeit pro Frame (verhindert Todesspirale nach Hängern)
+const MAX_FRAME_TIME: f32 = 0.25;
+
+// Entity-Anzahl im Stresstest
+const STRESS_DEBRIS: usize = 2000;
+const STRESS_BULLETS: usize = 2000;
+
+// Alle Screens des Spiels. Pro Frame ist genau einer aktiv und liest die Eingaben.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameScreen {
+    Title,
+    Playing,
+    Paused,
+    GameOver,
+    SkillTree, // Overlay über Title oder Game Over
+    Settings,  // Overlay über Title oder Game Over
+}
+
+impl GameScreen {
+    fn is_overlay(&self) -> bool {
+        matches!(self, GameScreen::SkillTree | GameScreen::Settings)
+    }
+}
+
+pub struct Game {
+    pub screen: GameScreen,
+    pub previous_screen: GameScreen, // Screen unter einem Overlay
+    pub world: World,
+    pub recorder: Option<ReplayRecorder>,
+    pub playback: Option<ReplayPlayback>,
+    pub replay_verified: Option<bool>,
+    pub highscores: BTreeMap<Difficulty, i32>, // Bester Score pro Schwierigkeit
+    pub bosses_defeated: u32,                  // Über alle Runs, zählt für Skillpunkte
+    pub accumulator: f32,
+    pub fixed_seed: Option<u32>,
+    pub stress: bool,             // Stresstest-Szene statt normalem Run
+    pub sim_time_ms: f32,         // Dauer der Simulation im letzten Frame
+    pub mouse_fire_blocked: bool, // Klick aus einem Menü feuert erst nach dem Loslassen
+    pub music_manager: MusicManager,
+    pub settings_ui: SettingsUI,
+    pub fps_counter: FpsCounter,
+    pub skill_tree_manager: SkillTreeManager,
+    pub quit: bool,
+}
+
+impl Game {
+    pub fn new(music_manager: MusicManager, fixed_seed: Option<u32>) -> Self {
+        let save = load_save();
+        let mut game = Self {
+            screen: GameScreen::Title,
+            previous_screen: GameScreen::Title,
+            world: World::new(
+                Arena::logical(),
+                fixed_seed.unwrap_or_else(random_seed),
+                save.settings.difficulty,
+            ),
+            recorder: None,
+            playback: None,
+            replay_verified: None,
+            highscores: save.highscores,
+            bosses_defeated: save.bosses_defeated,
+            accumulator: 0.0,
+            fixed_seed,
+            stress: false,
+            sim_time_ms: 0.0,
+            mouse_fire_blocked: false,
+            music_manager,
+            settings_ui: SettingsUI::new(),
+            fps_counter: FpsCounter::new(),
+            skill_tree_manager: SkillTreeManager::new(),
+            quit: false,
+        };
+        game.enter_screen(GameScreen::Title);
+        game
+    }
+
+    pub fn highscore(&self, difficulty: Difficulty) -> i32 {
+        self.highscores.get(&difficulty).copied().unwrap_or(0)
+    }
+
+    // ---- Screen-Wechsel ----
+
+    // Wechsel zwischen Hauptscreens mit Exit- und Enter-Hook
+    pub fn change_screen(&mut self, next: GameScreen) {
+        let current = self.screen;
+        self.exit_screen(current);
+        self.screen = next;
+        self.enter_screen(next);
+    }
+
+    // Overlay öffnen, ohne den darunterliegenden Screen zu verlassen
+    fn open_overlay(&mut self, overlay: GameScreen) {
+        self.previous_screen = self.screen;
+        self.screen = overlay;
+        self.enter_screen(overlay);
+    }
+
+    // Zurück zum Screen unter dem Overlay
+    fn close_overlay(&mut self) {
+        let overlay = self.screen;
+        self.exit_screen(overlay);
+        self.screen = self.previous_screen;
+    }
+
+    fn enter_screen(&mut self, screen: GameScreen) {
+        match screen {
+            GameScreen::Title => self.play_music("menu"),
+            GameScreen::Playing => {
+                // Bei Twin-Stick ersetzt das Fadenkreuz den Mauszeiger
+                show_mouse(!self.aims_with_mouse());
+                // Der Klick auf "Resume" o.ä. soll keinen Schuss auslösen
+                self.mouse_fire_blocked = true;
+                self.play_music("gameplay");
+            }
+            GameScreen::Paused => {}
+            GameScreen::GameOver => {
+                self.play_music("menu");
+                self.finish_run();
+            }
+            GameScreen::SkillTree => {}
+            GameScreen::Settings => self.settings_ui.open(),
+        }
+    }
+
+    fn exit_screen(&mut self, screen: GameScreen) {
+        match screen {
+            GameScreen::Playing => {
+                self.accumulator = 0.0;
+                show_mouse(true);
+            }
+            GameScreen::Settings if self.settings_ui.have_volume_changes => {
+                self.music_manager.refresh_settings();
+                self.settings_ui.have_volume_changes = false;
+            }
+            _ => {}
+        }
+    }
+
+    fn play_music(&mut self, track: &str) {
+        if self.music_manager.current_track().map(|t| t.as_str()) != Some(track) {
+            self.music_manager.play(track);
+        }
+    }
+
+    // ---- Runs ----
+
+    // Neuer Run mit frischer Welt und den aktuellen Skills
+    pub fn start_run(&mut self) {
+        self.world = World::new(
+            Arena::logical(),
+            self.fixed_seed.unwrap_or_else(random_seed),
+            self.settings_ui.difficulty(),
+        );
+        if self.settings_ui.adaptive() {
+            self.world.adaptive = Some(AdaptiveDirector::new());
+        }
+        self.world.player.weapon = self.settings_ui.loadout();
+        self.world.player.heat_enabled = self.settings_ui.heat();
+        self.skill_tree_manager
+            .apply_to_player(&mut self.world.player);
+        self.recorder = Some(ReplayRecorder::new(
+            &self.world,
+            self.skill_tree_manager.skill_list(),
+            self.settings_ui.control_scheme(),
+        ));
+        self.playback = None;
+        self.replay_verified = None;
+        self.stress = false;
+        self.change_screen(GameScreen::Playing);
+    }
+
+    // Benchmark mit tausenden Entities; wird weder aufgezeichnet noch gewertet
+    pub fn start_stress_run(&mut self) {
+        self.start_run();
+        self.recorder = None;
+        self.stress = true;
+        self.world.player.max_hp = 1.0e9;
+        self.world.player.hp = self.world.player.max_hp;
+        self.world.fill_stress_scene(STRESS_DEBRIS, STRESS_BULLETS);
+    }
+
+    // Replay abspielen: gleicher Seed, gleiche Arena, gleiche Skills
+    pub fn start_replay(&mut self, replay: Replay) {
+        let world = World::new(
+            Arena::new(replay.arena_width, replay.arena_height),
+            replay.seed,
+            replay.difficulty,
+        );
+        // Mit anderen Assets aufgenommen, würde nicht mehr gleich ablaufen
+        if asset_hash(&world) != replay.asset_hash {
+            return;
+        }
+        self.world = world;
+        if replay.adaptive {
+            self.world.adaptive = Some(AdaptiveDirector::new());
+        }
+        self.world.player.weapon = replay.weapon;
+        self.world.player.heat_enabled = replay.heat;
+        SkillTreeManager::apply_skill_list(&replay.skills, &mut self.world.player);
+        self.recorder = None;
+        self.playback = Some(ReplayPlayback::new(replay));
+        self.replay_verified = None;
+        self.stress = false;
+        self.change_screen(GameScreen::Playing);
+    }
+
+    // Run auswerten: Replay speichern bzw. prüfen, Highscore und Skill Points
+    fn finish_run(&mut self) {
+        let score = self.world.score;
+        let difficulty = self.world.difficulty;
+
+        // Aufzeichnung speichern, sobald der Run vorbei ist
+        if let Some(recorder) = self.recorder.take() {
+            let replay = recorder.finish(score);
+            save_replay(&replay, LAST_RUN_FILE);
+            if score > self.highscore(difficulty) {
+                save_replay(&replay, BEST_RUN_FILE);
+            }
+        }
+
+        // Replay prüfen: gleiches Ende nach gleich vielen Ticks mit gleichem Score
+        // Replays zählen nicht für den Highscore
+        if let Some(playback) = self.playback.as_ref() {
+            self.replay_verified = Some(
+                playback.is_finished()
+                    && self.world.player.is_destroyed()
+                    && score == playback.replay.final_score,
+            );
+            return;
+        }
+
+        if self.stress {
+            return;
+        }
+
+        if score > self.highscore(difficulty) {
+            self.highscores.insert(difficulty, score);
+            update_highscore(difficulty, score);
+        }
+
+        let bosses = self.world.stats.bosses_defeated;
+        if bosses > 0 {
+            self.bosses_defeated += bosses;
+            add_bosses_defeated(bosses);
+        }
+
+        // Check if player earned skill points (bester Score über alle Schwierigkeiten)
+        let best_score = self.highscores.values().copied().max().unwrap_or(0);
+        let skill_points_earned =
+            SkillTreeManager::calculate_skill_points(best_score, self.bosses_defeated);
+        let manager = &mut self.skill_tree_manager;
+        if skill_points_earned > manager.total_skill_points_earned {
+            let new_points = skill_points_earned - manager.total_skill_points_earned;
+            for _ in 0..new_points {
+                manager.earn_skill_point();
+            }
+        }
+    }
+
+    // ---- Frame ----
+
+    pub fn update(&mut self) {
+        match self.screen {
+            GameScreen::Title => self.update_title(),
+            GameScreen::Playing => self.update_playing(),
+            GameScreen::Paused => self.update_paused(),
+            GameScreen::GameOver => self.update_game_over(),
+            GameScreen::SkillTree => self.update_skill_tree(),
+            GameScreen::Settings => self.update_settings(),
+        }
+    }
+
+    pub fn draw(&mut self) {
+        if self.screen.is_overlay() {
+            self.draw_screen(self.previous_screen);
+        }
+        self.draw_screen(self.screen);
+    }
+
+    fn draw_screen(&mut self, screen: GameScreen) {
+        match screen {
+            GameScreen::Title => self.draw_title(),
+            GameScreen::Playing => self.draw_playing(),
+            GameScreen::Paused => {
+                self.draw_playing();
+                self.draw_paused();
+            }
+            GameScreen::GameOver => self.draw_game_over(),
+            GameScreen::SkillTree => self.skill_tree_manager.draw_and_handle_input(),
+            GameScreen::Settings => self.settings_ui.update_and_draw(),
+        }
+    }
+
+    // ---- Title ----
+
+    fn update_title(&mut self) {
+        if is_key_pressed(KeyCode::Enter) {
+            self.start_run();
+        } else if is_key_pressed(KeyCode::T) {
+            self.open_overlay(GameScreen::SkillTree);
+        } else if is_key_pressed(KeyCode::Left) {
+            self.settings_ui.cycle_difficulty(-1);
+        } else if is_key_pressed(KeyCode::Right) {
+            self.settings_ui.cycle_difficulty(1);
+        } else if is_key_pressed(KeyCode::A) {
+            self.settings_ui.toggle_adaptive();
+        } else if is_key_pressed(KeyCode::L) {
+            self.settings_ui.cycle_loadout();
+        } else if is_key_pressed(KeyCode::H) {
+            self.settings_ui.toggle_heat();
+        } else if is_key_pressed(KeyCode::M) {
+            self.settings_ui.toggle_control_scheme();
+        } else if self.settings_ui.button_clicked() {
+            self.open_overlay(GameScreen::Settings);
+        } else if is_key_pressed(KeyCode::Escape) {
+            // Nur auf dem Title-Screen beendet ESC das Spiel
+            self.quit = true;
+        }
+    }
+
+    fn draw_title(&self) {
+        set_camera(&self.world.arena.camera());
+        for (i, s) in self.world.stars.iter().enumerate() {
+            s.draw(i, 1.0);
+        }
+        set_default_camera();
+
+        let title_font = screen_height() * 0.1;
+        let text_font = screen_height() * 0.04;
+        let small_font = screen_height() * 0.025;
+
+        draw_centered_text("gtRust", screen_height() * 0.35, title_font, SKYBLUE);
+        let difficulty = self.settings_ui.difficulty();
+        draw_centered_text(
+            &format!("Difficulty: < {} >", difficulty.label()),
+            screen_height() * 0.45,
+            text_font,
+            WHITE,
+        );
+        draw_centered_text(
+            &format!("Highscore: {}", self.highscore(difficulty)),
+            screen_height() * 0.5,
+            text_font,
+            YELLOW,
+        );
+        draw_centered_text(
+            &format!(
+                "Adaptive Director: {} (A)",
+                if self.settings_ui.adaptive() {
+                    "ON"
+                } else {
+                    "OFF"
+                }
+            ),
+            screen_height() * 0.54,
+            small_font,
+            GRAY,
+        );
+        let loadout = self.settings_ui.loadout();
+        draw_centered_text(
+            &format!("Loadout: {} (L)", loadout.label()),
+            screen_height() * 0.57,
+            small_font,
+            loadout.color(),
+        );
+        draw_centered_text(
+            &format!(
+                "Weapon Heat: {} (H)",
+                if self.settings_ui.heat() { "ON" } else { "OFF" }
+            ),
+            screen_height() * 0.6,
+            small_font,
+            GRAY,
+        );
+        draw_centered_text(
+            &format!(
+                "Controls: {} (M)",
+                self.settings_ui.control_scheme().label()
+            ),
+            screen_height() * 0.63,
+            small_font,
+            GRAY,
+        );
+        draw_centered_text(
+            "Press ENTER to Start | LEFT/RIGHT Difficulty | Press T for Skill Tree | ESC to Quit",
+            screen_height() * 0.67,
+            small_font,
+            GRAY,
+        );
+
+        self.settings_ui.draw_main_ui();
+    }
+
+    // ---- Playing ----
+
+    fn update_playing(&mut self) {
+        if is_key_pressed(KeyCode::Escape) || is_key_pressed(KeyCode::P) {
+            self.change_screen(GameScreen::Paused);
+            return;
+        }
+
+        // Alle Entitäten in festen Schritten updaten
+        let input = InputState::capture(
+            self.settings_ui.control_scheme(),
+            &self.world.arena.camera(),
+            self.world.player.get_position(),
+            &mut self.mouse_fire_blocked,
+        );
+        let mut game_over = false;
+        let sim_start = std::time::Instant::now();
+        self.accumulator += get_frame_time().min(MAX_FRAME_TIME);
+        while self.accumulator >= FIXED_DT && !game_over {
+            // Beim Replay kommen die Eingaben aus der Aufzeichnung
+            let tick_input = match self.playback.as_mut() {
+                Some(playback) => match playback.next_input() {
+                    Some(recorded) => recorded,
+                    None => {
+                        game_over = true; // Aufzeichnung zu Ende
+                        break;
+                    }
+                },
+                None => input,
+            };
+            if let Some(recorder) = self.recorder.as_mut() {
+                recorder.record(&tick_input);
+            }
+            game_over = self.world.update(FIXED_DT, &tick_input);
+            self.accumulator -= FIXED_DT;
+        }
+        if self.stress {
+            self.world.fill_stress_scene(STRESS_DEBRIS, STRESS_BULLETS);
+        }
+        self.sim_time_ms = sim_start.elapsed().as_secs_f32() * 1000.0;
+        self.fps_counter.update();
+
+        // Musik folgt der Intensität des adaptiven Directors
+        if let Some(adaptive) = &self.world.adaptive {
+            self.music_manager.set_intensity(adaptive.normalized());
+        }
+
+        if game_over {
+            self.change_screen(GameScreen::GameOver);
+        }
+    }
+
+    fn draw_playing(&self) {
+        // Alle Entitäten im Spielfeld zeichnen (zwischen den Ticks interpoliert)
+        set_camera(&self.world.arena.camera());
+        draw_entities(&self.world, self.accumulator / FIXED_DT);
+        set_default_camera();
+
+        // HUD in Fensterkoordinaten
+        draw_hud(&self.world, &self.fps_counter, self.playback.is_some());
+        if self.aims_with_mouse() {
+            draw_reticle(Vec2::from(mouse_position()), screen_height() * 0.02);
+        }
+        if self.stress {
+            draw_text(
+                &format!(
+                    "STRESS | Debris: {} | Bullets: {} | Sim: {:.2} ms",
+                    self.world.debris.len(),
+                    self.world.bullets.len(),
+                    self.sim_time_ms
+                ),
+                screen_width() * 0.02,
+                screen_height() * 0.18,
+                screen_height() * 0.025,
+                ORANGE,
+            );
+        }
+    }
+
+    // Twin-Stick im Live-Run (im Replay kommt das Zielen aus der Aufzeichnung)
+    fn aims_with_mouse(&self) -> bool {
+        self.playback.is_none() && self.settings_ui.control_scheme() == ControlScheme::TwinStick
+    }
+
+    // ---- Paused ----
+
+    fn update_paused(&mut self) {
+        if is_key_pressed(KeyCode::Escape) || is_key_pressed(KeyCode::P) {
+            self.change_screen(GameScreen::Playing);
+            return;
+        }
+
+        if !is_mouse_button_pressed(MouseButton::Left) {
+            return;
+        }
+        let mouse = Vec2::from(mouse_position());
+        let clicked = pause_menu_buttons()
+            .into_iter()
+            .find(|(rect, _)| rect.contains(mouse))
+            .map(|(_, action)| action);
+
+        match clicked {
+            Some(PauseAction::Resume) => self.change_screen(GameScreen::Playing),
+            Some(PauseAction::Restart) => {
+                // Abgebrochene Runs werden nicht gewertet
+                self.recorder = None;
+                self.start_run();
+            }
+            Some(PauseAction::Settings) => self.open_overlay(GameScreen::Settings),
+            Some(PauseAction::QuitToTitle) => {
+                self.recorder = None;
+                self.change_screen(GameScreen::Title);
+            }
+            None => {}
+        }
+    }
+
+    fn draw_paused(&self) {
+        draw_rectangle(
+            0.0,
+            0.0,
+            screen_width(),
+            screen_height(),
+            Color::new(0.0, 0.0, 0.0, 0.5),
+        );
+        draw_centered_text(
+            "PAUSED",
+            screen_height() * 0.3,
+            screen_height() * 0.08,
+            WHITE,
+        );
+
+        let mouse = Vec2::from(mouse_position());
+        for (rect, action) in pause_menu_buttons() {
+            draw_button(rect, action.label(), rect.contains(mouse));
+        }
+
+        draw_centered_text(
+            "Press P or ESC to Resume",
+            screen_height() * 0.85,
+            screen_height() * 0.025,
+            GRAY,
+        );
+    }
+
+    // ---- Game Over ----
+
+    fn update_game_over(&mut self) {
+        if is_key_pressed(KeyCode::R) {
+            self.start_run();
+        } else if is_key_pressed(KeyCode::V) {
+            // Letzten Run als Replay ansehen
+            if let Some(replay) = load_replay(LAST_RUN_FILE) {
+                self.start_replay(replay);
+            }
+        } else if is_key_pressed(KeyCode::T) {
+            self.open_overlay(GameScreen::SkillTree);
+        } else if self.settings_ui.button_clicked() {
+            self.open_overlay(GameScreen::Settings);
+        } else if is_key_pressed(KeyCode::Escape) {
+            self.change_screen(GameScreen::Title);
+        }
+    }
+
+    fn draw_game_over(&self) {
+        let score = self.world.score;
+
+        // Game Over Screen
+        let title_font = screen_height() * 0.08;
+        let text_font = screen_height() * 0.04;
+        let small_font = screen_height() * 0.025;
+
+        draw_centered_text(
+            "GAME OVER!",
+            screen_height() / 2.0 - screen_height() * 0.1,
+            title_font,
+            RED,
+        );
+        draw_centered_text(
+            &format!(
+                "Highscore ({}): {}",
+                self.world.difficulty.label(),
+                self.highscore(self.world.difficulty)
+            ),
+            screen_height() / 2.0 + screen_height() * 0.15,
+            text_font,
+            YELLOW,
+        );
+        draw_centered_text(
+            &format!("Final Score: {}", score),
+            screen_height() / 2.0,
+            text_font,
+            WHITE,
+        );
+
+        // Skill Points anzeigen
+        draw_centered_text(
+            &format!(
+                "Available Skill Points: {}",
+                self.skill_tree_manager.available_skill_points
+            ),
+            screen_height() / 2.0 + screen_height() * 0.05,
+            text_font,
+            GREEN,
+        );
+
+        // Statistik des Runs
+        let stats = &self.world.stats;
+        draw_centered_text(
+            &format!(
+                "Wave: {} | Destroyed: {} | Gunships: {} | Bosses: {} | Escaped: {}",
+                stats.waves_reached,
+                stats.debris_destroyed,
+                stats.gunships_destroyed,
+                stats.bosses_defeated,
+                stats.debris_escaped,
+            ),
+            screen_height() / 2.0 + screen_height() * 0.115,
+            small_font,
+            LIGHTGRAY,
+        );
+        draw_centered_text(
+            &format!(
+                "Hits: {} ({} crit) | Items: {} | Weapons: {} | Damage taken: {:.1}",
+                stats.bullet_hits,
+                stats.critical_hits,
+                stats.items_picked_up,
+                stats.weapons_picked_up,
+                stats.damage_taken
+            ),
+            screen_height() / 2.0 + screen_height() * 0.15,
+            small_font,
+            LIGHTGRAY,
+        );
+
+        draw_centered_text(
+            &format!("Seed: {}", format_seed_code(self.world.seed)),
+            screen_height() / 2.0 + screen_height() * 0.2,
+            small_font,
+            GRAY,
+        );
+
+        if let Some(verified) = self.replay_verified {
+            let (replay_text, replay_color) = if verified {
+                ("Replay verified", GREEN)
+            } else {
+                ("Replay diverged", RED)
+            };
+            draw_centered_text(
+                replay_text,
+                screen_height() / 2.0 + screen_height() * 0.24,
+                small_font,
+                replay_color,
+            );
+        }
+
+        draw_centered_text(
+            "Press R to Restart | Press T for Skill Tree | Press V for Replay | ESC for Title",
+            screen_height() / 2.0 + screen_height() * 0.08,
+            small_font,
+            GRAY,
+        );
+
+        self.settings_ui.draw_main_ui();
+    }
+
+    // ---- Overlays ----
+
+    fn update_skill_tree(&mut self) {
+        if is_key_pressed(KeyCode::T) || is_key_pressed(KeyCode::Escape) {
+            self.close_overlay();
+        }
+    }
+
+    fn update_settings(&mut self) {
+        // Save, Cancel und ESC schließen das Modal im letzten Frame
+        if !self.settings_ui.is_open() {
+            self.close_overlay();
+        }
+    }
+}
+
+// Buttons im Pause-Menü
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PauseAction {
+    Resume,
+    Restart,
+    Settings,
+    QuitToTitle,
+}
+
+impl PauseAction {
+    fn label(&self) -> &'static str {
+        match self {
+            PauseAction::Resume => "Resume",
+            PauseAction::Restart => "Restart",
+            PauseAction::Settings => "Settings",
+            PauseAction::QuitToTitle => "Quit to Title",
+        }
+    }
+}
+
+// Button-Positionen, untereinander in der Bildschirmmitte
+fn pause_menu_buttons() -> [(Rect, PauseAction); 4] {
+    let button_w = (screen_width() * 0.2).clamp(180.0, 360.0);
+    let button_h = (screen_height() * 0.07).clamp(40.0, 64.0);
+    let spacing = button_h * 0.3;
+    let x = (screen_width() - button_w) / 2.0;
+    let first_y = screen_height() * 0.38;
+
+    [
+        PauseAction::Resume,
+        PauseAction::Restart,
+        PauseAction::Settings,
+        PauseAction::QuitToTitle,
+    ]
+    .map(|action| {
+        let i = action as usize as f32;
+        let rect = Rect::new(x, first_y + i * (button_h + spacing), button_w, button_h);
+        (rect, action)
+    })
+}
+
+fn draw_centered_text(text: &str, y: f32, font_size: f32, color: Color) {
+    let size = measure_text(text, None, font_size as u16, 1.0);
+    draw_text(
+        text,
+        screen_width() / 2.0 - size.width / 2.0,
+        y,
+        font_size,
+        color,
+    );
+}
+
+// Fadenkreuz am Mauszeiger für das Twin-Stick-Zielen
+fn draw_reticle(pos: Vec2, radius: f32) {
+    let color = Color::new(0.5, 1.0, 0.6, 0.9);
+    draw_circle_lines(pos.x, pos.y, radius, 2.0, color);
+    for dir in [Vec2::X, -Vec2::X, Vec2::Y, -Vec2::Y] {
+        let inner = pos + dir * radius * 0.5;
+        let outer = pos + dir * radius * 1.5;
+        draw_line(inner.x, inner.y, outer.x, outer.y, 2.0, color);
+    }
+    draw_circle(pos.x, pos.y, 1.5, color);
+}
+
+// alpha: Anteil zwischen letztem und nächstem Tick für die Render-Interpolation
+fn draw_entities(world: &World, alpha: f32) {
+    // Sterne zeichnen
+    for (i, s) in world.stars.iter().enumerate() {
+        s.draw(i, alpha);
+    }
+
+    // Items zeichnen
+    world.item_manager.draw();
+    for pickup in &world.weapon_pickups {
+        pickup.draw();
+    }
+
+    // Entitäten zeichnen
+    world.player.draw(alpha);
+    world.beam.draw(&world.player, alpha);
+    for b in &world.bullets {
+        b.draw(alpha);
+    }
+    for d in &world.debris {
+        d.draw(alpha);
+    }
+    for g in &world.gunships {
+        g.draw(alpha);
+    }
+    if let Some(boss) = &world.boss {
+        boss.draw(alpha);
+    }
+    for ft in &world.floating_texts {
+        ft.draw();
+    }
+    for explosion in &world.explosions {
+        explosion.draw(alpha);
+    }
+
+    // Rand des Spielfelds
+    draw_rectangle_lines(
+        0.0,
+        0.0,
+        world.arena.width,
+        world.arena.height,
+        2.0,
+        Color::new(0.3, 0.3, 0.4, 1.0),
+    );
+}
+
+// Großer HP-Balken des Bosses oben in der Mitte, ein Abschnitt pro Phase
+fn draw_boss_bar(boss: &Boss, font_size: f32) {
+    let bar_w = screen_width() * 0.5;
+    let bar_h = screen_height() * 0.025;
+    let bar_x = (screen_width() - bar_w) / 2.0;
+    let bar_y = screen_height() * 0.05;
+
+    draw_centered_text(
+        &format!("MOTHERSHIP {}", boss.number),
+        bar_y - font_size * 0.3,
+        font_size,
+        Color::new(1.0, 0.5, 0.8, 1.0),
+    );
+
+    draw_rectangle(bar_x, bar_y, bar_w, bar_h, Color::new(0.3, 0.1, 0.1, 0.8));
+    draw_rectangle(
+        bar_x,
+        bar_y,
+        bar_w * boss.displayed_hp_progress.max(0.0),
+        bar_h,
+        Color::new(0.9, 0.2, 0.5, 0.9),
+    );
+
+    // Trennlinien zwischen den Abschnitten
+    for i in 1..BOSS_SEGMENTS {
+        let x = bar_x + bar_w * i as f32 / BOSS_SEGMENTS as f32;
+        draw_line(x, bar_y, x, bar_y + bar_h, 3.0, BLACK);
+    }
+
+    draw_rectangle_lines(bar_x, bar_y, bar_w, bar_h, 2.0, WHITE);
+}
+
+fn draw_hud(world: &World, fps_counter: &FpsCounter, is_replay: bool) {
+    let player = &world.player;
+    let score = world.score;
+
+    // UI skaliert mit Bildschirmgröße
+    let font_size = screen_height() * 0.04; // 4% der Bildschirmhöhe
+    let small_font = screen_height() * 0.025; // 2.5% der Bildschirmhöhe
+
+    // Score anzeigen (mit Multiplikator)
+    let score_text = if player.points_multiplier > 1.0 {
+        format!("Score: {} ({}x)", score, player.points_multiplier as i32)
+    } else {
+        format!("Score: {}", score)
+    };
+
+    draw_text(
+        &score_text,
+        screen_width() * 0.02,
+        screen_height() * 0.06,
+        font_size,
+        if player.points_multiplier > 1.0 {
+            YELLOW
+        } else {
+            WHITE
+        },
+    );
+
+    // Welle und Spawn-Rate anzeigen
+    let director = &world.director;
+    let wave_text = if director.resting {
+        format!("Wave {} cleared", director.wave_number)
+    } else {
+        format!(
+            "Wave {}: {}",
+            director.wave_number,
+            director.current_wave().name
+        )
+    };
+    draw_text(
+        &wave_text,
+        screen_width() * 0.02,
+        screen_height() * 0.09,
+        small_font,
+        Color::new(0.6, 0.9, 1.0, 1.0),
+    );
+    let spawn_text = match &world.adaptive {
+        Some(adaptive) => format!(
+            "Spawn Rate: {:.1}s | Intensity: {:.2} | Tension: {:.2}",
+            world.spawn_rate * adaptive.spawn_rate_scale(),
+            adaptive.intensity,
+            adaptive.tension
+        ),
+        None => format!("Spawn Rate: {:.1}s", world.spawn_rate),
+    };
+    draw_text(
+        &spawn_text,
+        screen_width() * 0.02,
+        screen_height() * 0.12,
+        small_font,
+        GRAY,
+    );
+
+    // Seed-Code des Runs anzeigen (zum Teilen)
+    draw_text(
+        &format!("Seed: {}", format_seed_code(world.seed)),
+        screen_width() * 0.02,
+        screen_height() * 0.15,
+        small_font,
+        GRAY,
+    );
+
+    // Kennzeichnung während ein Replay läuft
+    if is_replay {
+        draw_text(
+            "REPLAY",
+            screen_width() * 0.02,
+            screen_height() * 0.18,
+            small_font,
+            RED,
+        );
+    }
+
+    if let Some(boss) = &world.boss {
+        draw_boss_bar(boss, small_font);
+    }
+
+    // Aktive Effekte in der oberen rechten Ecke anzeigen
+    let mut effect_y = screen_height() * 0.06;
+    for effect in &player.active_effects {
+        let effect_name = match effect.effect_type {
+            ItemType::Shield => "SHIELD",
+            ItemType::SpeedBoost => "SPEED",
+            ItemType::SlowMotion => "SLOW-MO",
+            ItemType::Magnet => "MAGNET",
+            ItemType::PhaseShift => "PHASE",
+            ItemType::TimeFreeze => "FREEZE",
+            ItemType::DoublePoints => "2X POINTS",
+            ItemType::Overdrive => "OVERDRIVE",
+        };
+
+        let effect_text = format!("{}: {:.1}s", effect_name, effect.remaining_time);
+        let text_width = measure_text(&effect_text, None, small_font as u16, 1.0).width;
+
+        draw_text(
+            &effect_text,
+            screen_width() - text_width - screen_width() * 0.02,
+            effect_y,
+            small_font,
+            YELLOW,
+        );
+        effect_y += small_font * 1.2;
+    }
+
+    // Aktuelle Waffe über der Steuerung
+    let weapon_text = format!("Weapon: {}", player.weapon.label());
+    draw_text(
+        &weapon_text,
+        screen_width() * 0.02,
+        screen_height() - screen_height() * 0.06,
+        small_font,
+        player.weapon.color(),
+    );
+
+    // Energieleiste des Beams neben dem Waffennamen
+    if player.weapon == WeaponType::Beam {
+        let text_width = measure_text(&weapon_text, None, small_font as u16, 1.0).width;
+        let bar_x = screen_width() * 0.03 + text_width;
+        let bar_y = screen_height() - screen_height() * 0.06 - small_font * 0.6;
+        let bar_w = screen_width() * 0.12;
+        let bar_h = small_font * 0.6;
+        let beam = &world.beam;
+        draw_rectangle(bar_x, bar_y, bar_w, bar_h, Color::new(0.2, 0.2, 0.2, 0.8));
+        draw_rectangle(
+            bar_x,
+            bar_y,
+            bar_w * beam.energy_progress(),
+            bar_h,
+            if beam.depleted {
+                Color::new(0.6, 0.3, 0.3, 1.0)
+            } else {
+                player.weapon.color()
+            },
+        );
+        draw_rectangle_lines(bar_x, bar_y, bar_w, bar_h, 1.0, WHITE);
+    }
+
+    // Steuerung
+    let controls = if player.aim.is_some() {
+        "WASD to move | MOUSE = Aim | LMB/SPACE = Shoot | SHIFT/C = Charge | P/ESC = Pause"
+    } else {
+        "WASD or arrow keys to move | SPACE = Shoot | SHIFT/C = Charge | P/ESC = Pause"
+    };
+    draw_text(
+        controls,
+        screen_width() * 0.02,
+        screen_height() - screen_height() * 0.03,
+        small_font,
+        GRAY,
+    );
+    fps_counter.draw();
+}
@@ -0,0 +1,209 @@
+use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+// Bit-Positionen für die kompakte Speicherung in Replays
+const LEFT: u16 = 1 << 0;
+const RIGHT: u16 = 1 << 1;
+const UP: u16 = 1 << 2;
+const DOWN: u16 = 1 << 3;
+const SHOOT: u16 = 1 << 4;
+const CHARGE: u16 = 1 << 5;
+const AIM: u16 = 1 << 6;
+// Zielwinkel in den restlichen 9 Bits, auf 512 Stufen gerundet (~0.7°)
+const AIM_SHIFT: u16 = 7;
+const AIM_STEPS: u16 = 512;
+
+// Steuerungsschema, wird in den Settings gespeichert
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ControlScheme {
+    #[default]
+    Classic, // Geschossen wird in Flugrichtung
+    TwinStick, // WASD bewegt, die Maus zielt, Linksklick feuert
+}
+
+impl ControlScheme {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ControlScheme::Classic => "Classic",
+            ControlScheme::TwinStick => "Twin-Stick",
+        }
+    }
+
+    pub fn toggle(&self) -> ControlScheme {
+        match self {
+            ControlScheme::Classic => ControlScheme::TwinStick,
+            ControlScheme::TwinStick => ControlScheme::Classic,
+        }
+    }
+}
+
+// Momentaufnahme der Eingaben für einen Simulationsschritt.
+// Die Simulation liest nur diesen Zustand, nie direkt die Tastatur.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct InputState {
+    pub left: bool,
+    pub right: bool,
+    pub up: bool,
+    pub down: bool,
+    pub shoot: bool,
+    pub charge: bool,     // Halten lädt den Aufladeschuss, Loslassen feuert
+    pub aim: Option<f32>, // Twin-Stick: Zielwinkel (0 = oben), schon gerundet
+}
+
+impl InputState {
+    pub fn from_keyboard() -> Self {
+        Self {
+            left: is_key_down(KeyCode::Left) || is_key_down(KeyCode::A),
+            right: is_key_down(KeyCode::Right) || is_key_down(KeyCode::D),
+            up: is_key_down(KeyCode::Up) || is_key_down(KeyCode::W),
+            down: is_key_down(KeyCode::Down) || is_key_down(KeyCode::S),
+            shoot: is_key_down(KeyCode::Space),
+            charge: is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::C),
+            aim: None,
+        }
+    }
+
+    // Eingaben je nach Schema. Bei Twin-Stick zielt das Schiff auf den Mauszeiger,
+    // der über die Kamera in Spielfeld-Koordinaten umgerechnet wird.
+    // mouse_blocked: Linksklick ignorieren, bis die Taste einmal losgelassen wurde
+    pub fn capture(
+        scheme: ControlScheme,
+        camera: &Camera2D,
+        ship: Vec2,
+        mouse_blocked: &mut bool,
+    ) -> Self {
+        let mut input = Self::from_keyboard();
+        let mouse_shoot = mouse_fire(is_mouse_button_down(MouseButton::Left), mouse_blocked);
+        if scheme == ControlScheme::TwinStick {
+            let target = camera.screen_to_world(Vec2::from(mouse_position()));
+            let delta = target - ship;
+            input.shoot |= mouse_shoot;
+            input.aim = Some(quantize_aim(delta.y.atan2(delta.x) + FRAC_PI_2));
+        }
+        input
+    }
+
+    pub fn to_bits(self) -> u16 {
+        let mut bits = 0;
+        for (pressed, bit) in [
+            (self.left, LEFT),
+            (self.right, RIGHT),
+            (self.up, UP),
+            (self.down, DOWN),
+            (self.shoot, SHOOT),
+            (self.charge, CHARGE),
+        ] {
+            if pressed {
+                bits |= bit;
+            }
+        }
+        if let Some(angle) = self.aim {
+            bits |= AIM | (aim_step(angle) << AIM_SHIFT);
+        }
+        bits
+    }
+
+    pub fn from_bits(bits: u16) -> Self {
+        Self {
+            left: bits & LEFT != 0,
+            right: bits & RIGHT != 0,
+            up: bits & UP != 0,
+            down: bits & DOWN != 0,
+            shoot: bits & SHOOT != 0,
+            charge: bits & CHARGE != 0,
+            aim: (bits & AIM != 0).then(|| step_angle(bits >> AIM_SHIFT)),
+        }
+    }
+
+    // Normierte Bewegungsrichtung (diagonal nicht schneller)
+    pub fn move_direction(&self) -> Vec2 {
+        let mut dir = Vec2::ZERO;
+        if self.left {
+            dir.x -= 1.0;
+        }
+        if self.right {
+            dir.x += 1.0;
+        }
+        if self.up {
+            dir.y -= 1.0;
+        }
+        if self.down {
+            dir.y += 1.0;
+        }
+        dir.normalize_or_zero()
+    }
+}
+
+fn aim_step(angle: f32) -> u16 {
+    (angle.rem_euclid(TAU) / TAU * AIM_STEPS as f32).round() as u16 % AIM_STEPS
+}
+
+fn step_angle(step: u16) -> f32 {
+    step as f32 * TAU / AIM_STEPS as f32
+}
+
+// Die Sperre fällt, sobald die Taste einmal losgelassen ist
+fn mouse_fire(mouse_down: bool, blocked: &mut bool) -> bool {
+    *blocked &= mouse_down;
+    mouse_down && !*blocked
+}
+
+// Winkel so runden, wie er im Replay landet, damit Live-Run und Replay gleich rechnen
+fn quantize_aim(angle: f32) -> f32 {
+    step_angle(aim_step(angle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bits_round_trip() {
+        // Alle Tasten-Kombinationen, mit und ohne Zielwinkel
+        for bits in 0..(1 << 7) {
+            let input = InputState::from_bits(bits);
+            assert_eq!(input.to_bits(), bits);
+            assert_eq!(InputState::from_bits(input.to_bits()), input);
+        }
+        // Jede Zielstufe in den Bits 7-15
+        for step in 0..AIM_STEPS {
+            let bits = SHOOT | AIM | (step << AIM_SHIFT);
+            let input = InputState::from_bits(bits);
+            assert_eq!(input.aim, Some(step_angle(step)));
+            assert_eq!(input.to_bits(), bits);
+        }
+    }
+
+    #[test]
+    fn quantize_aim_rounds_to_steps() {
+        let step = TAU / AIM_STEPS as f32;
+        assert_eq!(quantize_aim(0.0), 0.0);
+        assert_eq!(quantize_aim(step * 0.4), 0.0);
+        assert_eq!(quantize_aim(step * 0.6), step);
+        // Negative und volle Umdrehungen landen im Bereich 0..TAU
+        assert_eq!(quantize_aim(-step), step_angle(AIM_STEPS - 1));
+        assert_eq!(quantize_aim(TAU - step * 0.1), 0.0);
+        assert!((quantize_aim(TAU + 1.0) - quantize_aim(1.0)).abs() < 1e-5);
+
+        // Gerundete Winkel überstehen den Weg durchs Replay unverändert
+        for i in 0..100 {
+            let aim = quantize_aim(i as f32 * 0.37 - 5.0);
+            let input = InputState {
+                aim: Some(aim),
+                ..Default::default()
+            };
+            assert_eq!(InputState::from_bits(input.to_bits()).aim, Some(aim));
+        }
+    }
+
+    #[test]
+    fn mouse_fire_waits_for_release() {
+        let mut blocked = true;
+        assert!(!mouse_fire(true, &mut blocked));
+        assert!(!mouse_fire(true, &mut blocked));
+        assert!(!mouse_fire(false, &mut blocked));
+        assert!(!blocked);
+        assert!(mouse_fire(true, &mut blocked));
+    }
+}
@@ -1,9 +1,13 @@
-use crate::floating_text::FloatingText;
+use crate::events::{EventQueue, GameEvent};
 use crate::player::Player;
+use crate::rng::Rng;
+use crate::spatial_grid::SpatialGrid;
+use crate::world::Arena;
 use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ItemType {
     Shield,       // Schutz für ein paar Sekunden
     SpeedBoost,   // Schneller bewegen, aber schwerer zu kontrollieren
@@ -16,11 +20,28 @@ pub enum ItemType {
                   // BlackHole,    // Saugt Gegner in der Nähe weg
 }
 
+impl ItemType {
+    // Text für den Pickup-Hinweis
+    pub fn pickup_text(&self) -> &'static str {
+        match self {
+            ItemType::Shield => "SHIELD!",
+            ItemType::SpeedBoost => "SPEED!",
+            ItemType::SlowMotion => "SLOW-MO!",
+            ItemType::Magnet => "MAGNET!",
+            ItemType::PhaseShift => "PHASE!",
+            ItemType::TimeFreeze => "FREEZE!",
+            ItemType::DoublePoints => "2X POINTS!",
+            ItemType::Overdrive => "OVERDRIVE!",
+            // ItemType::BlackHole => "BLACK HOLE!",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Item {
     pub position: Vec2,
     pub item_type: ItemType,
-    pub spawn_time: f32,
+    pub age: f32, // Sekunden seit dem Spawn
     pub lifetime: f32,
     pub blink_start_time: f32,
     pub size: f32,
@@ -34,11 +55,13 @@ pub struct ItemManager {
     pub items: Vec<Item>,
     spawn_timer: f32,
     spawn_interval: f32,
+    pub spawn_interval_scale: f32, // Vom adaptiven Director gesetzt, 1.0 = unverändert
     item_colors: HashMap<ItemType, Color>,
+    grid: SpatialGrid, // Raster über `items` für die Magnet-Abfrage
 }
 
 impl ItemManager {
-    pub fn new() -> Self {
+    pub fn new(spawn_interval: f32) -> Self {
         let mut item_colors = HashMap::new();
 
         // Farben für verschiedene Item-Typen
@@ -55,32 +78,47 @@ impl ItemManager {
         Self {
             items: Vec::new(),
             spawn_timer: 0.0,
-            spawn_interval: 5.0, // Alle 5 Sekunden ein neues Item
+            spawn_interval, // Sekunden bis zum nächsten Item
+            spawn_interval_scale: 1.0,
             item_colors,
+            grid: SpatialGrid::new(128.0),
         }
     }
 
-    pub fn update(&mut self, dt: f32, player: &Player) {
+    pub fn update(&mut self, dt: f32, player: &Player, arena: Arena, rng: &mut Rng) {
         // Spawn-Timer aktualisieren
         self.spawn_timer += dt;
-        if self.spawn_timer >= self.spawn_interval {
-            self.spawn_random_item();
+        if self.spawn_timer >= self.spawn_interval * self.spawn_interval_scale {
+            self.spawn_random_item(arena, rng);
             self.spawn_timer = 0.0;
         }
 
-        // Items aktualisieren
-        for item in &mut self.items {
-            item.rotation += dt * 2.0; // Langsame Rotation
-            item.pulse_phase += dt * 4.0; // Pulsieren für Animation
-
-            // Magnet-Effekt: Items werden zum Spieler gezogen
-            if player.magnet_range > 0.0 {
+        // Magnet-Effekt: Items werden zum Spieler gezogen
+        if player.magnet_range > 0.0 {
+            self.grid.rebuild(
+                arena,
+                self.items
+                    .iter()
+                    .map(|item| (item.position.x, item.position.y, item.size)),
+            );
+            let mut candidates = Vec::new();
+            self.grid
+                .query_circle(player.x, player.y, player.magnet_range, &mut candidates);
+            for i in candidates {
+                let item = &mut self.items[i];
                 let distance_to_player = (item.position - player.get_position()).length();
                 if distance_to_player <= player.magnet_range {
                     item.attracted_to_player = true;
                     item.attraction_speed = 200.0; // Pixel pro Sekunde
                 }
             }
+        }
+
+        // Items aktualisieren
+        for item in &mut self.items {
+            item.age += dt;
+            item.rotation += dt * 2.0; // Langsame Rotation
+            item.pulse_phase += dt * 4.0; // Pulsieren für Animation
 
             // Item zum Spieler bewegen wenn angezogen
             if item.attracted_to_player {
@@ -90,17 +128,10 @@ impl ItemManager {
         }
 
         // Abgelaufene Items entfernen
-        let current_time = get_time() as f32;
-        self.items
-            .retain(|item| current_time - item.spawn_time < item.lifetime);
+        self.items.retain(|item| item.age < item.lifetime);
     }
 
-    pub fn check_pickups(
-        &mut self,
-        player: &mut Player,
-        floating_texts: &mut Vec<FloatingText>,
-    ) -> Vec<ItemType> {
-        let mut picked_up_items = Vec::new();
+    pub fn check_pickups(&mut self, player: &mut Player, events: &mut EventQueue) {
         let player_pos = player.get_position();
         let pickup_radius = player.get_pickup_radius();
 
@@ -109,39 +140,41 @@ impl ItemManager {
             if distance <= pickup_radius + item.size {
                 // Item aufgesammelt!
                 player.apply_item_effect(item.item_type.clone());
-                picked_up_items.push(item.item_type.clone());
-
-                // Floating Text für Pickup
-                let text = match item.item_type {
-                    ItemType::Shield => "SHIELD!",
-                    ItemType::SpeedBoost => "SPEED!",
-                    ItemType::SlowMotion => "SLOW-MO!",
-                    ItemType::Magnet => "MAGNET!",
-                    ItemType::PhaseShift => "PHASE!",
-                    ItemType::TimeFreeze => "FREEZE!",
-                    ItemType::DoublePoints => "2X POINTS!",
-                    ItemType::Overdrive => "OVERDRIVE!",
-                    // ItemType::BlackHole => "BLACK HOLE!",
-                };
-
-                floating_texts.push(FloatingText::new_with_text(
-                    item.position.x,
-                    item.position.y,
-                    text.to_string(),
-                    Color::new(1.0, 1.0, 0.0, 1.0),
-                ));
+                events.push(GameEvent::ItemPickedUp {
+                    item_type: item.item_type.clone(),
+                    x: item.position.x,
+                    y: item.position.y,
+                });
 
                 false // Item entfernen
             } else {
                 true // Item behalten
             }
         });
+    }
 
-        picked_up_items
+    fn spawn_random_item(&mut self, arena: Arena, rng: &mut Rng) {
+        let item_type = Self::random_item_type(rng);
+        self.spawn_item_of_type(item_type, arena, rng);
     }
 
-    fn spawn_random_item(&mut self) {
-        let item_types = vec![
+    // Bestimmtes Item an zufälliger Stelle (z.B. aus einem Wellen-Script)
+    pub fn spawn_item_of_type(&mut self, item_type: ItemType, arena: Arena, rng: &mut Rng) {
+        let position = Vec2::new(
+            rng.gen_range(50.0, arena.width - 50.0),
+            rng.gen_range(50.0, arena.height - 50.0),
+        );
+        self.spawn_item(position, item_type);
+    }
+
+    // Garantiertes Item an einer bestimmten Stelle (z.B. Boss-Beute)
+    pub fn drop_item(&mut self, position: Vec2, rng: &mut Rng) {
+        let item_type = Self::random_item_type(rng);
+        self.spawn_item(position, item_type);
+    }
+
+    fn random_item_type(rng: &mut Rng) -> ItemType {
+        let item_types = [
             ItemType::Shield,
             ItemType::SpeedBoost,
             ItemType::SlowMotion,
@@ -153,16 +186,14 @@ impl ItemManager {
             // ItemType::BlackHole,
         ];
 
-        let random_type = item_types[rand::gen_range(0, item_types.len())].clone();
-        let current_time = get_time() as f32;
+        item_types[rng.gen_range(0, item_types.len())].clone()
+    }
 
+    fn spawn_item(&mut self, position: Vec2, item_type: ItemType) {
         let item = Item {
-            position: Vec2::new(
-                rand::gen_range(50.0, screen_width() - 50.0),
-                rand::gen_range(50.0, screen_height() - 50.0),
-            ),
-            item_type: random_type,
-            spawn_time: current_time,
+            position,
+            item_type,
+            age: 0.0,
             lifetime: 12.0,        // 12 Sekunden Lebensdauer
             blink_start_time: 9.0, // Nach 9 Sekunden anfangen zu blinken
             size: 20.0,
@@ -179,7 +210,7 @@ impl ItemManager {
         let current_time = get_time() as f32;
 
         for item in &self.items {
-            let age = current_time - item.spawn_time;
+            let age = item.age;
             let base_color = *self
                 .item_colors
                 .get(&item.item_type)
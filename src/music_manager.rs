@@ -27,10 +27,10 @@ impl MusicManager {
     // Spielt einen Track ab - stoppt automatisch den vorherigen
     pub fn play(&mut self, name: &str) {
         // Aktuellen Track stoppen
-        if let Some(current_name) = &self.current_music {
-            if let Some(current_sound) = self.tracks.get(current_name) {
-                stop_sound(current_sound);
-            }
+        if let Some(current_name) = &self.current_music
+            && let Some(current_sound) = self.tracks.get(current_name)
+        {
+            stop_sound(current_sound);
         }
 
         // Neuen Track abspielen
@@ -53,14 +53,23 @@ impl MusicManager {
             self.music_volume = new_volume;
 
             // Laufende Musik sofort anpassen
-            if let Some(current_name) = &self.current_music {
-                if let Some(sound) = self.tracks.get(current_name) {
-                    set_sound_volume(sound, new_volume);
-                }
+            if let Some(current_name) = &self.current_music
+                && let Some(sound) = self.tracks.get(current_name)
+            {
+                set_sound_volume(sound, new_volume);
             }
         }
     }
 
+    // Lautstärke des laufenden Tracks an die Intensität (0..1) koppeln
+    pub fn set_intensity(&mut self, intensity: f32) {
+        if let Some(current_name) = &self.current_music
+            && let Some(sound) = self.tracks.get(current_name)
+        {
+            set_sound_volume(sound, self.music_volume * (0.6 + 0.4 * intensity));
+        }
+    }
+
     // Aktuellen Track stoppen
     // pub fn stop(&mut self) {
     //     if let Some(current_name) = &self.current_music {
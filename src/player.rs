@@ -1,8 +1,29 @@
 use macroquad::prelude::*;
 
 use crate::bullet::Bullet;
+use crate::events::{EventQueue, GameEvent};
 use crate::help_fn::lerp;
+use crate::input::InputState;
 use crate::items::ItemType;
+use crate::rng::Rng;
+use crate::weapon::WeaponType;
+use crate::world::Arena;
+
+// Wie schnell der Rückstoß abklingt (pro Sekunde)
+const KNOCKBACK_DAMPING: f32 = 6.0;
+// Dauer des KineticShielding-Speedbursts nach einem Treffer
+const KINETIC_BOOST_DURATION: f32 = 2.0;
+// Aufladeschuss: kürzer gehalten verpufft er, länger lädt nicht weiter
+const MIN_CHARGE_TIME: f32 = 0.3;
+const MAX_CHARGE_TIME: f32 = 1.5;
+const PLASMA_MAX_CHARGE_TIME: f32 = 2.5;
+
+// Optionales Hitzemodell: Salven heizen auf, bei 1.0 sind die Kanonen überhitzt
+const HEAT_PER_VOLLEY: f32 = 0.15; // Mal Cooldown-Faktor der Waffe, gleicht Feuerraten aus
+const HEAT_PER_CHARGED_SHOT: f32 = 0.3;
+const COOLING_RATE: f32 = 0.12; // pro Sekunde
+const OVERHEAT_COOLING_RATE: f32 = 0.25; // Während die Kanonen gesperrt sind
+const OVERHEAT_LOCK: f32 = 1.5; // Sekunden Sperre nach dem Überhitzen
 
 #[derive(Debug, Clone)]
 pub struct ActiveEffect {
@@ -14,12 +35,15 @@ pub struct ActiveEffect {
 pub struct Player {
     pub x: f32,
     pub y: f32,
+    pub prev_x: f32, // Position des vorherigen Ticks (für Interpolation)
+    pub prev_y: f32,
     pub size: f32,
     pub base_size: f32, // Für Overdrive-Effekt
     pub shoot_cooldown: f32,
     pub max_shoot_ccooldown: f32,
     pub base_shoot_cooldown: f32, // Basis-Wert für Effekte
-    pub rotation: f32,
+    pub rotation: f32,            // Rumpf zeigt in Flugrichtung
+    pub aim: Option<f32>,         // Twin-Stick: Geschütze zielen unabhängig vom Rumpf
     pub displayed_hp_progress: f32,
     pub hp: f32,
     pub max_hp: f32,
@@ -30,6 +54,9 @@ pub struct Player {
     pub can_phase_through: bool,
     pub active_effects: Vec<ActiveEffect>,
     pub magnet_range: f32,
+    pub knockback_x: f32, // Geschwindigkeit durch Rückstoß, klingt ab
+    pub knockback_y: f32,
+    pub weapon: WeaponType, // Aktuelle Waffe, wechselt durch Waffen-Kisten
 
     // Skill Tree related fields
     pub damage_multiplier: f32,
@@ -70,6 +97,7 @@ pub struct Player {
     pub overdrive_hitbox_reduction: f32,
     pub temporal_surge_strength: f32,
     pub energy_overflow_bonus: f32,
+    pub golden_touch: bool, // Ultimate: Bonuspunkte bei jedem 10. Pickup
 
     // Timers and counters for skill effects
     pub last_immunity_time: f32,
@@ -78,24 +106,38 @@ pub struct Player {
     pub adaptive_armor_stacks: u8,
     pub last_black_hole_time: f32,
     pub pickup_count: u32,
+    pub elapsed_time: f32,        // Simulationszeit seit Spawn
+    pub kinetic_boost_timer: f32, // Restdauer des KineticShielding-Bursts
+    pub charge_time: f32,         // Wie lange der Aufladeschuss schon gehalten wird
+    pub plasma_cannon: bool,      // Ultimate: viel stärkerer Aufladeschuss
+    pub heat_enabled: bool,       // Hitzemodell aus den Settings
+    pub heat: f32,                // 0..1
+    pub overheat_timer: f32,      // Restdauer der Sperre nach dem Überhitzen
+    pub heat_gain_multiplier: f32,
 }
 
 impl Player {
-    pub fn new() -> Self {
+    pub fn new(arena: Arena) -> Self {
         let max_hp = 3.0;
-        let base_size = screen_width().min(screen_height()) * 0.03;
+        let base_size = arena.min_side() * 0.03;
         let base_shoot_cooldown = 0.5;
-        let base_speed = screen_width().max(screen_height()) * 0.25;
+        let base_speed = arena.max_side() * 0.25;
+
+        let x = arena.width / 2.0;
+        let y = arena.height - arena.height * 0.1;
 
         Player {
-            x: screen_width() / 2.0,
-            y: screen_height() - screen_height() * 0.1,
+            x,
+            y,
+            prev_x: x,
+            prev_y: y,
             size: base_size,
             base_size,
             shoot_cooldown: 0.0,
             max_shoot_ccooldown: base_shoot_cooldown,
             base_shoot_cooldown,
             rotation: 0.0,
+            aim: None,
             displayed_hp_progress: 1.0,
             hp: max_hp,
             max_hp,
@@ -106,6 +148,9 @@ impl Player {
             can_phase_through: false,
             active_effects: Vec::new(),
             magnet_range: 0.0,
+            knockback_x: 0.0,
+            knockback_y: 0.0,
+            weapon: WeaponType::Blaster,
 
             // Initialize skill tree fields with default values
             damage_multiplier: 1.0,
@@ -146,6 +191,7 @@ impl Player {
             overdrive_hitbox_reduction: 0.0,
             temporal_surge_strength: 0.0,
             energy_overflow_bonus: 0.0,
+            golden_touch: false,
 
             // Initialize timers
             last_immunity_time: 0.0,
@@ -154,22 +200,67 @@ impl Player {
             adaptive_armor_stacks: 0,
             last_black_hole_time: 0.0,
             pickup_count: 0,
+            elapsed_time: 0.0,
+            kinetic_boost_timer: 0.0,
+            charge_time: 0.0,
+            plasma_cannon: false,
+            heat_enabled: false,
+            heat: 0.0,
+            overheat_timer: 0.0,
+            heat_gain_multiplier: 1.0,
         }
     }
 
-    pub fn update(&mut self, bullets: &mut Vec<Bullet>) {
+    pub fn update(
+        &mut self,
+        dt: f32,
+        input: &InputState,
+        arena: Arena,
+        bullets: &mut Vec<Bullet>,
+        rng: &mut Rng,
+        events: &mut EventQueue,
+    ) {
+        self.elapsed_time += dt;
+        self.prev_x = self.x;
+        self.prev_y = self.y;
+
         // Aktive Effekte updaten
-        self.update_effects();
+        self.update_effects(dt, arena, events);
 
         // Update skill-based timers and effects
-        self.update_skill_effects();
+        self.update_skill_effects(dt);
+
+        self.aim = input.aim;
+
+        // Aufladeschuss: Halten lädt, Loslassen feuert, sobald die Kanone bereit ist.
+        // Teilt sich den Cooldown mit dem normalen Schuss und läuft vorher, damit nie
+        // beide im selben Tick feuern. Der Beam hat keinen Aufladeschuss.
+        if !self.weapon.fires_projectiles() {
+            self.charge_time = 0.0;
+        } else if input.charge {
+            self.charge_time = (self.charge_time + dt).min(self.max_charge_time());
+        } else if self.charge_time > 0.0 && self.shoot_cooldown <= 0.0 {
+            // Überhitzt verpufft die Ladung
+            if self.charge_time >= MIN_CHARGE_TIME && !self.is_overheated() {
+                let charge = self.charge_progress();
+                bullets.push(Bullet::new_charged(self, charge, arena, rng));
+                self.add_heat(HEAT_PER_CHARGED_SHOT * (1.0 + charge));
+                self.shoot_cooldown = self.max_shoot_ccooldown;
+            }
+            self.charge_time = 0.0;
+        }
 
-        // Schießen mit Space (mit Rotation)
-        if is_key_down(KeyCode::Space) && self.shoot_cooldown <= 0.0 {
+        // Schießen mit Space (mit Rotation bzw. zum Mauszeiger)
+        // Der Beam feuert keine Projektile, er läuft in World
+        if input.shoot
+            && self.shoot_cooldown <= 0.0
+            && self.weapon.fires_projectiles()
+            && !self.is_overheated()
+        {
             // Startposition vorne am Schiff (rotiert)
             let front_offset = Vec2::new(0.0, -self.size);
-            let cos_a = self.rotation.cos();
-            let sin_a = self.rotation.sin();
+            let cos_a = self.aim_angle().cos();
+            let sin_a = self.aim_angle().sin();
             let rotated_offset = Vec2::new(
                 front_offset.x * cos_a - front_offset.y * sin_a,
                 front_offset.x * sin_a + front_offset.y * cos_a,
@@ -178,64 +269,29 @@ impl Player {
             let bullet_x = self.x + rotated_offset.x;
             let bullet_y = self.y + rotated_offset.y;
 
-            // Create primary bullet with skill modifiers
-            let mut bullet = Bullet::new(bullet_x, bullet_y, self.rotation);
-            bullet.damage *= self.damage_multiplier;
-            bullet.speed *= self.bullet_speed_multiplier;
-            bullet.size *= self.bullet_size_multiplier;
-            bullet.pierce_count = self.bullet_pierce_count;
-            bullets.push(bullet);
-
-            // Check for double shot chance
-            if rand::gen_range(0.0, 1.0) < self.double_shot_chance {
-                let spread_angle = 0.2; // Small spread for twin cannons
-                let mut bullet1 = Bullet::new(bullet_x, bullet_y, self.rotation - spread_angle);
-                bullet1.damage *= self.damage_multiplier;
-                bullet1.speed *= self.bullet_speed_multiplier;
-                bullet1.size *= self.bullet_size_multiplier;
-                bullet1.pierce_count = self.bullet_pierce_count;
-                bullets.push(bullet1);
-
-                let mut bullet2 = Bullet::new(bullet_x, bullet_y, self.rotation + spread_angle);
-                bullet2.damage *= self.damage_multiplier;
-                bullet2.speed *= self.bullet_speed_multiplier;
-                bullet2.size *= self.bullet_size_multiplier;
-                bullet2.pierce_count = self.bullet_pierce_count;
-                bullets.push(bullet2);
-            }
+            // Salve der aktuellen Waffe, Combat-Skills sind schon angewendet
+            let weapon = self.weapon;
+            bullets.extend(weapon.fire(bullet_x, bullet_y, self, arena, rng));
+            self.add_heat(HEAT_PER_VOLLEY * weapon.cooldown_scale());
 
             self.shoot_cooldown = self.max_shoot_ccooldown;
         }
 
-        let speed = self.base_speed * self.speed_multiplier;
-
-        // Bewegung mit Pfeiltasten oder WASD
-        let mut dx = 0.0f32;
-        let mut dy = 0.0f32;
-
-        if is_key_down(KeyCode::Left) || is_key_down(KeyCode::A) {
-            dx -= 1.0;
-        }
-        if is_key_down(KeyCode::Right) || is_key_down(KeyCode::D) {
-            dx += 1.0;
-        }
-        if is_key_down(KeyCode::Up) || is_key_down(KeyCode::W) {
-            dy -= 1.0;
-        }
-        if is_key_down(KeyCode::Down) || is_key_down(KeyCode::S) {
-            dy += 1.0;
+        let mut speed = self.base_speed * self.speed_multiplier;
+        if self.kinetic_boost_timer > 0.0 {
+            speed *= 1.0 + self.kinetic_shield_strength;
         }
 
-        // Normieren um diagonale Bewegung nicht schneller zu machen
-        if dx != 0.0 || dy != 0.0 {
-            let len = (dx * dx + dy * dy).sqrt();
-            dx /= len;
-            dy /= len;
-        }
+        // Bewegung mit Pfeiltasten oder WASD (bereits normiert)
+        let direction = input.move_direction();
+        let (dx, dy) = (direction.x, direction.y);
 
-        // Geschwindigkeit anwenden
-        self.x += dx * speed * get_frame_time();
-        self.y += dy * speed * get_frame_time();
+        // Geschwindigkeit anwenden, Rückstoß kommt obendrauf und klingt ab
+        self.x += (dx * speed + self.knockback_x) * dt;
+        self.y += (dy * speed + self.knockback_y) * dt;
+        let damping = (-KNOCKBACK_DAMPING * dt).exp();
+        self.knockback_x *= damping;
+        self.knockback_y *= damping;
 
         // Neuen Winkel speichern (arctangent)
         if dx != 0.0 || dy != 0.0 {
@@ -243,35 +299,39 @@ impl Player {
         }
 
         // Bildschirmgrenzen
-        self.x = self.x.clamp(self.size, screen_width() - self.size);
-        self.y = self.y.clamp(self.size, screen_height() - self.size);
+        self.x = self.x.clamp(self.size, arena.width - self.size);
+        self.y = self.y.clamp(self.size, arena.height - self.size);
 
         // Cooldown reduzieren
         if self.shoot_cooldown > 0.0 {
-            self.shoot_cooldown -= get_frame_time();
+            self.shoot_cooldown -= dt;
         }
+        self.update_heat(dt);
 
         // NEU: Animation des HP-Balkens
-        let target_progress = (self.hp.max(0.0) as f32) / (self.max_hp as f32);
+        let target_progress = self.hp.max(0.0) / self.max_hp;
         let lerp_speed = 8.0; // Wie schnell der Balken dem Ziel folgt (höher = schneller)
-        self.displayed_hp_progress = lerp(
-            self.displayed_hp_progress,
-            target_progress,
-            get_frame_time() * lerp_speed,
-        );
+        self.displayed_hp_progress =
+            lerp(self.displayed_hp_progress, target_progress, dt * lerp_speed);
     }
 
-    fn update_effects(&mut self) {
-        let dt = get_frame_time();
-
+    fn update_effects(&mut self, dt: f32, arena: Arena, events: &mut EventQueue) {
         // Effekte zeitlich reduzieren
         for effect in &mut self.active_effects {
             effect.remaining_time -= dt;
         }
 
         // Abgelaufene Effekte entfernen
-        self.active_effects
-            .retain(|effect| effect.remaining_time > 0.0);
+        self.active_effects.retain(|effect| {
+            if effect.remaining_time > 0.0 {
+                true
+            } else {
+                events.push(GameEvent::EffectExpired {
+                    effect_type: effect.effect_type.clone(),
+                });
+                false
+            }
+        });
 
         // Werte zurücksetzen
         self.speed_multiplier = 1.0;
@@ -279,37 +339,30 @@ impl Player {
         self.damage_reduction = 0.0;
         self.can_phase_through = false;
         self.magnet_range = 0.0;
-        self.max_shoot_ccooldown = self.base_shoot_cooldown;
+        let weapon_cooldown = self.base_shoot_cooldown * self.weapon.cooldown_scale();
+        self.max_shoot_ccooldown = weapon_cooldown;
         self.size = self.base_size;
 
-        // Aktive Effekte anwenden
+        // Aktive Effekte anwenden (Skill-Boni auf die Dauer sind schon in apply_item_effect enthalten)
         for effect in &self.active_effects {
-            let mut duration = effect.original_duration;
-
-            // Apply skill bonuses to effect duration
             match effect.effect_type {
                 ItemType::Shield => {
-                    duration += self.shield_duration_bonus;
                     self.damage_reduction = 0.5; // 50% weniger Schaden
                 }
                 ItemType::SpeedBoost => {
                     self.speed_multiplier = 2.0 * self.speed_boost_effectiveness;
                 }
                 ItemType::SlowMotion => {
-                    duration += self.slow_motion_duration_bonus;
-                    // Wird in main.rs für Gegner angewendet
+                    // Wird in world.rs für Gegner angewendet
                 }
                 ItemType::Magnet => {
-                    self.magnet_range =
-                        (screen_width().min(screen_height())) * 0.15 * self.magnet_range_multiplier;
+                    self.magnet_range = arena.min_side() * 0.15 * self.magnet_range_multiplier;
                 }
                 ItemType::PhaseShift => {
-                    duration += self.phase_duration_bonus;
                     self.can_phase_through = true;
                 }
                 ItemType::TimeFreeze => {
-                    duration += self.time_freeze_duration_bonus;
-                    // Wird in main.rs für Gegner angewendet
+                    // Wird in world.rs für Gegner angewendet
                 }
                 ItemType::DoublePoints => {
                     self.points_multiplier *= 2.0;
@@ -318,12 +371,9 @@ impl Player {
                     self.points_multiplier *= 3.0;
                     let hitbox_reduction = 1.0 - self.overdrive_hitbox_reduction;
                     self.size = self.base_size * 1.5 * hitbox_reduction; // Größere Hitbox mit Skill-Reduktion
-                    self.max_shoot_ccooldown = self.base_shoot_cooldown * 0.3; // Schneller schießen
+                    self.max_shoot_ccooldown = weapon_cooldown * 0.3; // Schneller schießen
                 }
             }
-
-            // Apply general item duration multiplier
-            duration *= self.item_effect_duration_multiplier;
         }
     }
 
@@ -369,8 +419,9 @@ impl Player {
             .any(|effect| &effect.effect_type == effect_type)
     }
 
-    pub fn draw(&self) {
+    pub fn draw(&self, alpha: f32) {
         let angle = self.rotation;
+        let pos = self.render_position(alpha);
 
         // Rotationsfunktion
         let rotate = |p: Vec2, angle: f32| -> Vec2 {
@@ -384,32 +435,76 @@ impl Player {
             let time = get_time() as f32;
             let shield_pulse = 0.8 + 0.2 * (time * 4.0).sin();
             let shield_color = Color::new(0.0, 0.5, 1.0, 0.3 * shield_pulse);
-            draw_circle(self.x, self.y, self.size * 2.0 * shield_pulse, shield_color);
+            draw_circle(pos.x, pos.y, self.size * 2.0 * shield_pulse, shield_color);
 
             // Shield-Ring
             draw_circle_lines(
-                self.x,
-                self.y,
+                pos.x,
+                pos.y,
                 self.size * 1.8,
                 3.0,
                 Color::new(0.0, 0.8, 1.0, shield_pulse),
             );
         }
 
+        // KineticShielding-Burst: Bewegungsstreifen
+        if self.kinetic_boost_timer > 0.0 {
+            let strength = (self.kinetic_boost_timer / KINETIC_BOOST_DURATION).min(1.0);
+            draw_circle_lines(
+                pos.x,
+                pos.y,
+                self.size * 1.5,
+                2.0,
+                Color::new(0.3, 1.0, 0.6, 0.7 * strength),
+            );
+        }
+
+        // Ladering des Aufladeschusses, schließt sich mit der Ladung
+        if self.charge_time > 0.0 {
+            let progress = self.charge_progress();
+            let ready = self.charge_time >= MIN_CHARGE_TIME;
+            let color = match (self.plasma_cannon, ready) {
+                (_, false) => Color::new(0.6, 0.6, 0.7, 0.6),
+                (false, true) => Color::new(0.5, 0.8, 1.0, 0.9),
+                (true, true) => Color::new(0.4, 1.0, 0.5, 0.9),
+            };
+            draw_arc(
+                pos.x,
+                pos.y,
+                48,
+                self.size * 1.9,
+                -90.0,
+                3.0,
+                360.0 * progress,
+                color,
+            );
+            // Voll geladen: pulsierender Kern vor der Nase
+            if progress >= 1.0 {
+                let time = get_time() as f32;
+                let nose = pos + self.aim_direction() * self.size;
+                draw_circle(
+                    nose.x,
+                    nose.y,
+                    self.size * (0.3 + 0.1 * (time * 12.0).sin()),
+                    Color::new(color.r, color.g, color.b, 0.6),
+                );
+            }
+        }
+
         // PhaseShift-Effekt
         if self.can_phase_through {
             let time = get_time() as f32;
             let phase_alpha = 0.3 + 0.4 * (time * 6.0).sin().abs();
             // Zeichne Spieler halbtransparent
-            self.draw_ship_with_alpha(angle, rotate, phase_alpha);
+            self.draw_ship_with_alpha(pos, angle, rotate, phase_alpha);
 
             // Zusätzliche Geist-Ringe
             for i in 0..3 {
                 let ring_size = self.size * (2.0 + i as f32 * 0.5);
                 let ring_alpha = phase_alpha * (0.5 - i as f32 * 0.1);
                 draw_circle_lines(
-                    self.x,
-                    self.y,
+                    pos.x,
+                    pos.y,
                     ring_size,
                     2.0,
                     Color::new(0.5, 0.8, 1.0, ring_alpha),
@@ -417,7 +512,7 @@ impl Player {
             }
         } else {
             // Normal zeichnen
-            self.draw_ship_with_alpha(angle, rotate, 1.0);
+            self.draw_ship_with_alpha(pos, angle, rotate, 1.0);
         }
 
         // Magnet-Effekt visualisieren
@@ -425,8 +520,8 @@ impl Player {
             let time = get_time() as f32;
             let magnet_pulse = 0.5 + 0.3 * (time * 3.0).sin();
             draw_circle_lines(
-                self.x,
-                self.y,
+                pos.x,
+                pos.y,
                 self.magnet_range * magnet_pulse,
                 2.0,
                 Color::new(1.0, 0.5, 0.0, 0.3),
@@ -441,8 +536,8 @@ impl Player {
                 let aura_size = self.size * (2.5 + i as f32 * 0.3);
                 let aura_alpha = 0.2 - i as f32 * 0.05;
                 draw_circle(
-                    self.x,
-                    self.y,
+                    pos.x,
+                    pos.y,
                     aura_size,
                     Color::new(1.0, 0.2, 0.0, aura_alpha * (time * 8.0).sin().abs()),
                 );
@@ -450,7 +545,13 @@ impl Player {
         }
     }
 
-    fn draw_ship_with_alpha(&self, angle: f32, rotate: fn(Vec2, f32) -> Vec2, alpha: f32) {
+    fn draw_ship_with_alpha(
+        &self,
+        pos: Vec2,
+        angle: f32,
+        rotate: fn(Vec2, f32) -> Vec2,
+        alpha: f32,
+    ) {
         // === HAUPTKÖRPER (Rumpf) ===
         let body_points = [
             Vec2::new(0.0, -self.size),                   // Spitze vorne
@@ -461,11 +562,11 @@ impl Player {
         ];
 
         // Rumpf zeichnen (mehrere Dreiecke für Form)
-        let rp1 = rotate(body_points[0], angle) + Vec2::new(self.x, self.y);
-        let rp2 = rotate(body_points[1], angle) + Vec2::new(self.x, self.y);
-        let rp3 = rotate(body_points[4], angle) + Vec2::new(self.x, self.y);
-        let rp4 = rotate(body_points[2], angle) + Vec2::new(self.x, self.y);
-        let rp5 = rotate(body_points[3], angle) + Vec2::new(self.x, self.y);
+        let rp1 = rotate(body_points[0], angle) + pos;
+        let rp2 = rotate(body_points[1], angle) + pos;
+        let rp3 = rotate(body_points[4], angle) + pos;
+        let rp4 = rotate(body_points[2], angle) + pos;
+        let rp5 = rotate(body_points[3], angle) + pos;
 
         // Hauptrumpf (dunkelblau)
         draw_triangle(rp1, rp2, rp3, Color::new(0.2, 0.4, 0.8, alpha));
@@ -478,30 +579,24 @@ impl Player {
             Vec2::new(-self.size * 0.15, -self.size * 0.2),
             Vec2::new(self.size * 0.15, -self.size * 0.2),
         ];
-        let cp1 = rotate(cockpit_points[0], angle) + Vec2::new(self.x, self.y);
-        let cp2 = rotate(cockpit_points[1], angle) + Vec2::new(self.x, self.y);
-        let cp3 = rotate(cockpit_points[2], angle) + Vec2::new(self.x, self.y);
+        let cp1 = rotate(cockpit_points[0], angle) + pos;
+        let cp2 = rotate(cockpit_points[1], angle) + pos;
+        let cp3 = rotate(cockpit_points[2], angle) + pos;
 
         draw_triangle(cp1, cp2, cp3, Color::new(0.6, 0.8, 1.0, 0.9 * alpha));
 
         // Die Flügel müssen hinten am Rumpf ein bisschen näher ans Raumschiff
         // === FLÜGEL ===
         // Linker Flügel (kleines Dreieck, das direkt an der linken Seite des Rumpfes ansetzt)
-        let wing_l1 = rotate(Vec2::new(-self.size * 0.25, self.size * 0.0), angle)
-            + Vec2::new(self.x, self.y); // Ansatz links
-        let wing_l2 = rotate(Vec2::new(-self.size * 0.8, self.size * 0.30), angle)
-            + Vec2::new(self.x, self.y); // außen, hinten (näher)
-        let wing_l3 =
-            rotate(Vec2::new(-self.size * 0.2, self.size * 0.5), angle) + Vec2::new(self.x, self.y); // hinten, an Rumpf (näher)
+        let wing_l1 = rotate(Vec2::new(-self.size * 0.25, self.size * 0.0), angle) + pos; // Ansatz links
+        let wing_l2 = rotate(Vec2::new(-self.size * 0.8, self.size * 0.30), angle) + pos; // außen, hinten (näher)
+        let wing_l3 = rotate(Vec2::new(-self.size * 0.2, self.size * 0.5), angle) + pos; // hinten, an Rumpf (näher)
         draw_triangle(wing_l1, wing_l2, wing_l3, Color::new(0.3, 0.5, 0.9, alpha));
 
         // Rechter Flügel (spiegelsymmetrisch)
-        let wing_r1 =
-            rotate(Vec2::new(self.size * 0.25, self.size * 0.0), angle) + Vec2::new(self.x, self.y); // Ansatz rechts
-        let wing_r2 =
-            rotate(Vec2::new(self.size * 0.8, self.size * 0.3), angle) + Vec2::new(self.x, self.y); // außen, hinten (näher)
-        let wing_r3 =
-            rotate(Vec2::new(self.size * 0.2, self.size * 0.5), angle) + Vec2::new(self.x, self.y); // hinten, an Rumpf (näher)
+        let wing_r1 = rotate(Vec2::new(self.size * 0.25, self.size * 0.0), angle) + pos; // Ansatz rechts
+        let wing_r2 = rotate(Vec2::new(self.size * 0.8, self.size * 0.3), angle) + pos; // außen, hinten (näher)
+        let wing_r3 = rotate(Vec2::new(self.size * 0.2, self.size * 0.5), angle) + pos; // hinten, an Rumpf (näher)
         draw_triangle(wing_r1, wing_r2, wing_r3, Color::new(0.3, 0.5, 0.9, alpha));
 
         // === BLINKENDE POSITIONSLICHTER ===
@@ -528,8 +623,7 @@ impl Player {
 
         // Pulsierendes Cockpit-Licht
         let pulse = (time * 5.0).sin() * 0.3 + 0.7;
-        let cockpit_center =
-            rotate(Vec2::new(0.0, -self.size * 0.4), angle) + Vec2::new(self.x, self.y);
+        let cockpit_center = rotate(Vec2::new(0.0, -self.size * 0.4), angle) + pos;
         draw_circle(
             cockpit_center.x,
             cockpit_center.y,
@@ -539,7 +633,7 @@ impl Player {
 
         // --- Triebwerk Offset (lokal nach "hinten") ---
         let engine_offset = Vec2::new(0.0, self.size * 0.7);
-        let world_engine = rotate(engine_offset, angle) + Vec2::new(self.x, self.y);
+        let world_engine = rotate(engine_offset, angle) + pos;
 
         // Flammenflickern
         let time = get_time() as f32;
@@ -589,7 +683,7 @@ impl Player {
             let local_pos = Vec2::new(rand_x, self.size * 0.8 + rand_y);
 
             // Rotation ins Weltkoordinatensystem
-            let world_pos = rotate(local_pos, angle) + Vec2::new(self.x, self.y);
+            let world_pos = rotate(local_pos, angle) + pos;
 
             let size = (1.5 + 0.5) * life;
             let color = Color::new(1.0, 0.7, 0.2, life * 0.8 * alpha);
@@ -599,11 +693,55 @@ impl Player {
             }
         }
 
+        // Geschützturm, dreht sich bei Twin-Stick zum Mauszeiger
+        if self.aim.is_some() {
+            let barrel_end = pos + self.aim_direction() * self.size * 1.1;
+            draw_line(
+                pos.x,
+                pos.y,
+                barrel_end.x,
+                barrel_end.y,
+                self.size * 0.16,
+                Color::new(0.75, 0.8, 0.85, alpha),
+            );
+            draw_circle(
+                pos.x,
+                pos.y,
+                self.size * 0.22,
+                Color::new(0.35, 0.4, 0.45, alpha),
+            );
+            draw_circle(
+                barrel_end.x,
+                barrel_end.y,
+                self.size * 0.08,
+                Color::new(0.5, 0.9, 1.0, alpha),
+            );
+        }
+
+        // Hitzebalken rechts neben dem Schiff
+        if self.heat_enabled && self.heat > 0.0 {
+            let bar_w = self.size * 0.2;
+            let bar_h = self.size * 2.0;
+            let bar_x = pos.x + self.size * 1.4;
+            let bar_y = pos.y - bar_h / 2.0;
+            let fill = bar_h * self.heat;
+            let color = if self.is_overheated() {
+                // Blinkt, solange die Kanonen gesperrt sind
+                let blink = (time * 12.0).sin() * 0.5 + 0.5;
+                Color::new(1.0, 0.2 * blink, 0.1, 0.6 + 0.4 * blink)
+            } else {
+                Color::new(1.0, 1.0 - self.heat * 0.8, 0.2, 0.9)
+            };
+            draw_rectangle(bar_x, bar_y, bar_w, bar_h, GRAY);
+            draw_rectangle(bar_x, bar_y + bar_h - fill, bar_w, fill, color);
+            draw_rectangle_lines(bar_x, bar_y, bar_w, bar_h, 1.0, WHITE);
+        }
+
         // Schuss-Cooldown-Balken
         let bar_width = self.size * 2.0;
         let bar_height = self.size * 0.2;
-        let bar_x = self.x - bar_width / 2.0;
-        let bar_y = self.y - self.size * 1.2;
+        let bar_x = pos.x - bar_width / 2.0;
+        let bar_y = pos.y - self.size * 1.2;
         let progress = 1.0 - (self.shoot_cooldown / self.max_shoot_ccooldown).max(0.0);
         draw_rectangle(bar_x, bar_y, bar_width, bar_height, GRAY);
         draw_rectangle(bar_x, bar_y, bar_width * progress, bar_height, BLUE);
@@ -613,8 +751,8 @@ impl Player {
             // Auch anzeigen, wenn Balken noch animiert
             let bar_w = self.size * 2.0;
             let bar_h = self.size * 0.2;
-            let bar_x = self.x - bar_w / 2.0;
-            let bar_y = self.y - self.size * 1.5;
+            let bar_x = pos.x - bar_w / 2.0;
+            let bar_y = pos.y - self.size * 1.5;
 
             // Hintergrund (rot für verlorene HP)
             draw_rectangle(bar_x, bar_y, bar_w, bar_h, RED);
@@ -632,14 +770,14 @@ impl Player {
         }
 
         // Aktive Effekte anzeigen
-        self.draw_active_effects();
+        self.draw_active_effects(pos);
     }
 
-    fn draw_active_effects(&self) {
+    fn draw_active_effects(&self, pos: Vec2) {
         let effect_bar_width = 100.0;
         let effect_bar_height = 8.0;
-        let start_x = self.x - (effect_bar_width + 5.0) / 2.0;
-        let start_y = self.y + self.size * 1.5;
+        let start_x = pos.x - (effect_bar_width + 5.0) / 2.0;
+        let start_y = pos.y + self.size * 1.5;
 
         for (i, effect) in self.active_effects.iter().enumerate() {
             let bar_y = start_y + i as f32 * (effect_bar_height + 1.0);
@@ -687,7 +825,7 @@ impl Player {
     }
 
     pub fn take_damage(&mut self, damage: f32) -> bool {
-        let current_time = get_time() as f32;
+        let current_time = self.elapsed_time;
 
         // Check reactive armor immunity
         if self.immunity_interval > 0.0
@@ -699,7 +837,6 @@ impl Player {
         // Apply damage with all reductions
         let mut final_damage = damage;
         final_damage *= 1.0 - self.damage_reduction; // Item-based reduction
-        final_damage *= 1.0 - self.debris_damage_reduction; // Skill-based debris reduction
         final_damage *= 1.0 - (self.adaptive_armor_stacks as f32 * self.adaptive_armor_rate); // Adaptive plating
 
         self.hp -= final_damage;
@@ -714,13 +851,25 @@ impl Player {
         }
 
         // Trigger kinetic shielding if available
-        if self.kinetic_shield_strength > 0.0 {
-            // This would trigger a temporary speed boost - handled in update_skill_effects
+        if self.kinetic_shield_strength > 0.0 && final_damage > 0.0 {
+            self.kinetic_boost_timer = KINETIC_BOOST_DURATION;
         }
 
         self.hp <= 0.0
     }
 
+    // Kollision mit Debris, zusätzlich mit Skill-basierter Debris-Reduktion
+    pub fn take_debris_damage(&mut self, damage: f32) -> bool {
+        self.take_damage(damage * (1.0 - self.debris_damage_reduction))
+    }
+
+    // Stoß bei einer Kollision, ImpactFrame dämpft ihn
+    pub fn apply_knockback(&mut self, impulse: Vec2) {
+        let factor = (1.0 - self.knockback_resistance).max(0.0);
+        self.knockback_x += impulse.x * factor;
+        self.knockback_y += impulse.y * factor;
+    }
+
     pub fn is_destroyed(&self) -> bool {
         self.hp <= 0.0
     }
@@ -729,13 +878,80 @@ impl Player {
         Vec2::new(self.x, self.y)
     }
 
+    // Winkel, in den die Geschütze zeigen (ohne Twin-Stick die Flugrichtung)
+    pub fn aim_angle(&self) -> f32 {
+        self.aim.unwrap_or(self.rotation)
+    }
+
+    // Einheitsvektor in Schussrichtung (Rotation 0 = nach oben)
+    pub fn aim_direction(&self) -> Vec2 {
+        let angle = self.aim_angle();
+        Vec2::new(angle.sin(), -angle.cos())
+    }
+
+    // Vorderspitze des Schiffs, dort starten Schüsse
+    pub fn nose(&self) -> Vec2 {
+        self.get_position() + self.aim_direction() * self.size
+    }
+
+    pub fn is_overheated(&self) -> bool {
+        self.overheat_timer > 0.0
+    }
+
+    pub fn add_heat(&mut self, amount: f32) {
+        if !self.heat_enabled {
+            return;
+        }
+        self.heat += amount * self.heat_gain_multiplier;
+        if self.heat >= 1.0 {
+            self.heat = 1.0;
+            self.overheat_timer = OVERHEAT_LOCK;
+        }
+    }
+
+    // Abkühlen, PowerCapacitors kühlt und entsperrt schneller
+    fn update_heat(&mut self, dt: f32) {
+        let cooling = if self.is_overheated() {
+            self.overheat_timer -= dt * self.reload_speed_multiplier;
+            OVERHEAT_COOLING_RATE
+        } else {
+            COOLING_RATE
+        };
+        self.heat = (self.heat - cooling * self.reload_speed_multiplier * dt).max(0.0);
+    }
+
+    fn max_charge_time(&self) -> f32 {
+        if self.plasma_cannon {
+            PLASMA_MAX_CHARGE_TIME
+        } else {
+            MAX_CHARGE_TIME
+        }
+    }
+
+    // Ladung des Aufladeschusses von 0 bis 1
+    pub fn charge_progress(&self) -> f32 {
+        self.charge_time / self.max_charge_time()
+    }
+
+    // Zwischen vorherigem und aktuellem Tick interpolierte Position
+    pub fn render_position(&self, alpha: f32) -> Vec2 {
+        Vec2::new(
+            lerp(self.prev_x, self.x, alpha),
+            lerp(self.prev_y, self.y, alpha),
+        )
+    }
+
     pub fn get_pickup_radius(&self) -> f32 {
         self.size * 1.5
     }
 
-    fn update_skill_effects(&mut self) {
-        let dt = get_frame_time();
-        let current_time = get_time() as f32;
+    fn update_skill_effects(&mut self, dt: f32) {
+        let current_time = self.elapsed_time;
+
+        // Kinetic shielding speed burst
+        if self.kinetic_boost_timer > 0.0 {
+            self.kinetic_boost_timer -= dt;
+        }
 
         // Health regeneration
         if self.health_regen_rate > 0.0 && current_time - self.last_regen_time >= 1.0 {
@@ -759,14 +975,10 @@ impl Player {
         }
     }
 
-    pub fn on_item_pickup(&mut self) {
+    // Returns true when Golden Touch triggers (every 10th pickup)
+    pub fn on_item_pickup(&mut self) -> bool {
         self.pickup_count += 1;
 
-        // Golden Touch effect (every 10th pickup)
-        if self.pickup_count % 10 == 0 {
-            // This would add bonus points - to be handled in main.rs
-        }
-
         // Energy overflow effect
         if self.energy_overflow_bonus > 0.0 {
             // Extend all active effects
@@ -779,5 +991,73 @@ impl Player {
         if self.temporal_surge_strength > 0.0 {
             // This would slow down the game temporarily - to be handled in main.rs
         }
+
+        // Golden Touch effect (every 10th pickup), bonus points are added in world.rs
+        self.golden_touch && self.pickup_count.is_multiple_of(10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn heated_player() -> Player {
+        let mut player = Player::new(Arena::logical());
+        player.heat_enabled = true;
+        player
+    }
+
+    #[test]
+    fn heat_is_ignored_without_heat_model() {
+        let mut player = Player::new(Arena::logical());
+        player.add_heat(5.0);
+        assert_eq!(player.heat, 0.0);
+        assert!(!player.is_overheated());
+    }
+
+    #[test]
+    fn heat_overheats_at_one_and_locks() {
+        let mut player = heated_player();
+        player.add_heat(0.6);
+        assert!((player.heat - 0.6).abs() < 1e-6);
+        assert!(!player.is_overheated());
+
+        player.add_heat(0.6);
+        assert_eq!(player.heat, 1.0);
+        assert!(player.is_overheated());
+    }
+
+    #[test]
+    fn overheat_lock_releases_after_lock_time() {
+        let mut player = heated_player();
+        player.add_heat(1.0);
+        let dt = 1.0 / 120.0;
+        let mut elapsed = 0.0;
+        while player.is_overheated() {
+            player.update_heat(dt);
+            elapsed += dt;
+        }
+        assert!((elapsed - OVERHEAT_LOCK).abs() < 2.0 * dt);
+        // Während der Sperre kühlt es schneller ab
+        let expected = 1.0 - OVERHEAT_COOLING_RATE * OVERHEAT_LOCK;
+        assert!((player.heat - expected).abs() < 0.01);
+    }
+
+    #[test]
+    fn heat_cools_down_and_power_capacitors_speed_it_up() {
+        let mut base = heated_player();
+        let mut skilled = heated_player();
+        skilled.reload_speed_multiplier = 1.1;
+        base.add_heat(0.5);
+        skilled.add_heat(0.5);
+
+        base.update_heat(1.0);
+        skilled.update_heat(1.0);
+        assert!((base.heat - (0.5 - COOLING_RATE)).abs() < 1e-6);
+        assert!(skilled.heat < base.heat);
+
+        // Nie unter null
+        base.update_heat(100.0);
+        assert_eq!(base.heat, 0.0);
     }
 }
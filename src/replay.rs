@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+use crate::difficulty::Difficulty;
+use crate::input::{ControlScheme, InputState};
+use crate::weapon::WeaponType;
+use crate::world::{TICK_RATE, World};
+
+// Bei jeder Änderung, die den Ablauf der Simulation verändert, erhöhen.
+// Ältere Replays werden dann abgelehnt statt still anders abzulaufen.
+pub const REPLAY_VERSION: u32 = 18;
+pub const LAST_RUN_FILE: &str = "replays/last_run.json";
+pub const BEST_RUN_FILE: &str = "replays/best_run.json";
+
+// Alles, was nötig ist, um einen Run Tick für Tick nachzuspielen
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Replay {
+    pub version: u32,
+    pub tick_rate: f32,
+    pub seed: u32,
+    pub arena_width: f32,
+    pub arena_height: f32,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    #[serde(default)]
+    pub adaptive: bool,
+    #[serde(default)]
+    pub weapon: WeaponType, // Startwaffe
+    #[serde(default)]
+    pub heat: bool,
+    #[serde(default)]
+    pub control_scheme: ControlScheme, // Nur zur Info, das Zielen steckt in den Eingaben
+    pub skills: Vec<String>, // Format wie im Savegame: "SkillName:Points"
+    pub final_score: i32,
+    pub asset_hash: u64, // Prüfsumme der Assets, mit denen aufgenommen wurde
+    pub inputs: Vec<(u16, u32)>, // Lauflängen-kodiert: (Eingabe-Bits, Anzahl Ticks)
+}
+
+// Zeichnet die Eingaben eines laufenden Runs auf
+pub struct ReplayRecorder {
+    replay: Replay,
+}
+
+impl ReplayRecorder {
+    pub fn new(world: &World, skills: Vec<String>, control_scheme: ControlScheme) -> Self {
+        Self {
+            replay: Replay {
+                version: REPLAY_VERSION,
+                tick_rate: TICK_RATE,
+                seed: world.seed,
+                arena_width: world.arena.width,
+                arena_height: world.arena.height,
+                difficulty: world.difficulty,
+                adaptive: world.adaptive.is_some(),
+                weapon: world.player.weapon,
+                heat: world.player.heat_enabled,
+                control_scheme,
+                skills,
+                final_score: 0,
+                asset_hash: asset_hash(world),
+                inputs: Vec::new(),
+            },
+        }
+    }
+
+    pub fn record(&mut self, input: &InputState) {
+        let bits = input.to_bits();
+        match self.replay.inputs.last_mut() {
+            Some((last_bits, ticks)) if *last_bits == bits => *ticks += 1,
+            _ => self.replay.inputs.push((bits, 1)),
+        }
+    }
+
+    pub fn finish(self, final_score: i32) -> Replay {
+        let mut replay = self.replay;
+        replay.final_score = final_score;
+        replay
+    }
+}
+
+// Liefert die aufgezeichneten Eingaben statt der Tastatur
+pub struct ReplayPlayback {
+    pub replay: Replay,
+    run_index: usize,
+    ticks_used: u32,
+}
+
+impl ReplayPlayback {
+    pub fn new(replay: Replay) -> Self {
+        Self {
+            replay,
+            run_index: 0,
+            ticks_used: 0,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.run_index >= self.replay.inputs.len()
+    }
+
+    // Eingabe für den nächsten Tick, None wenn die Aufzeichnung zu Ende ist
+    pub fn next_input(&mut self) -> Option<InputState> {
+        let (bits, ticks) = *self.replay.inputs.get(self.run_index)?;
+        self.ticks_used += 1;
+        if self.ticks_used >= ticks {
+            self.run_index += 1;
+            self.ticks_used = 0;
+        }
+        Some(InputState::from_bits(bits))
+    }
+}
+
+// Prüfsumme (FNV-1a) über die geladenen Assets. Ändern sich z.B. die
+// Debris-Archetypen oder Wellen, würde ein altes Replay sonst still anders ablaufen.
+pub fn asset_hash(world: &World) -> u64 {
+    let kinds = serde_json::to_string(&world.debris_kinds).unwrap_or_default();
+    let script = serde_json::to_string(&world.director.script).unwrap_or_default();
+    kinds
+        .bytes()
+        .chain(script.bytes())
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+}
+
+pub fn save_replay(replay: &Replay, path: &str) {
+    if let Some(dir) = std::path::Path::new(path).parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    if let Ok(json) = serde_json::to_string(replay) {
+        let _ = fs::write(path, json);
+    }
+}
+
+pub fn load_replay(path: &str) -> Option<Replay> {
+    let contents = fs::read_to_string(path).ok()?;
+    let replay: Replay = serde_json::from_str(&contents).ok()?;
+    // Ältere Formate oder andere Tickraten lassen sich nicht exakt nachspielen
+    if replay.version != REPLAY_VERSION || replay.tick_rate != TICK_RATE {
+        return None;
+    }
+    Some(replay)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::Arena;
+
+    fn input(bits: u16) -> InputState {
+        InputState::from_bits(bits)
+    }
+
+    #[test]
+    fn recording_is_run_length_encoded_and_plays_back() {
+        let world = World::new(Arena::new(1920.0, 1080.0), 7, Difficulty::Normal);
+        let mut recorder = ReplayRecorder::new(&world, Vec::new(), ControlScheme::Classic);
+        let ticks = [0b1, 0b1, 0b1, 0b10000, 0b10001, 0b10001, 0b1];
+        for &bits in &ticks {
+            recorder.record(&input(bits));
+        }
+
+        let replay = recorder.finish(123);
+        assert_eq!(replay.final_score, 123);
+        assert_eq!(
+            replay.inputs,
+            vec![(0b1, 3), (0b10000, 1), (0b10001, 2), (0b1, 1)]
+        );
+
+        let mut playback = ReplayPlayback::new(replay);
+        for &bits in &ticks {
+            assert!(!playback.is_finished());
+            assert_eq!(playback.next_input(), Some(input(bits)));
+        }
+        assert!(playback.is_finished());
+        assert_eq!(playback.next_input(), None);
+    }
+
+    #[test]
+    fn asset_hash_changes_with_assets() {
+        let mut world = World::new(Arena::new(1920.0, 1080.0), 7, Difficulty::Normal);
+        let hash = asset_hash(&world);
+        assert_eq!(
+            hash,
+            asset_hash(&World::new(
+                Arena::new(1920.0, 1080.0),
+                8,
+                Difficulty::Normal
+            ))
+        );
+
+        world.debris_kinds[0].damage += 1.0;
+        assert_ne!(asset_hash(&world), hash);
+        world.debris_kinds[0].damage -= 1.0;
+        assert_eq!(asset_hash(&world), hash);
+
+        world.director.script.waves[0].duration += 1.0;
+        assert_ne!(asset_hash(&world), hash);
+    }
+}
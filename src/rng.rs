@@ -0,0 +1,137 @@
+// Spieleigener Zufallsgenerator (SplitMix64). Jeder Run hat einen Seed,
+// aus dem getrennte Streams für Gameplay und Kosmetik abgeleitet werden.
+// So bleibt ein Run reproduzierbar, auch wenn sich Partikel-Effekte ändern.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+// Unterschiedliche Start-Offsets für die beiden Streams
+const GAMEPLAY_STREAM: u64 = 0x5EED_0000_0000_0001;
+const COSMETIC_STREAM: u64 = 0xC05E_7000_0000_0002;
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn gameplay(seed: u32) -> Self {
+        Self::new(seed as u64 ^ GAMEPLAY_STREAM)
+    }
+
+    pub fn cosmetic(seed: u32) -> Self {
+        Self::new(seed as u64 ^ COSMETIC_STREAM)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Gleichverteilt in [0, 1)
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    // Wie macroquad::rand::gen_range: low inklusive, high exklusiv
+    pub fn gen_range<T: RandomRange>(&mut self, low: T, high: T) -> T {
+        T::gen_range(self, low, high)
+    }
+
+    pub fn chance(&mut self, probability: f32) -> bool {
+        self.next_f32() < probability
+    }
+}
+
+pub trait RandomRange {
+    fn gen_range(rng: &mut Rng, low: Self, high: Self) -> Self;
+}
+
+impl RandomRange for f32 {
+    fn gen_range(rng: &mut Rng, low: f32, high: f32) -> f32 {
+        low + (high - low) * rng.next_f32()
+    }
+}
+
+impl RandomRange for usize {
+    fn gen_range(rng: &mut Rng, low: usize, high: usize) -> usize {
+        if high <= low {
+            return low;
+        }
+        low + (rng.next_u64() % (high - low) as u64) as usize
+    }
+}
+
+impl RandomRange for i32 {
+    fn gen_range(rng: &mut Rng, low: i32, high: i32) -> i32 {
+        if high <= low {
+            return low;
+        }
+        low + (rng.next_u64() % (high - low) as u64) as i32
+    }
+}
+
+impl RandomRange for u8 {
+    fn gen_range(rng: &mut Rng, low: u8, high: u8) -> u8 {
+        usize::gen_range(rng, low as usize, high as usize) as u8
+    }
+}
+
+// Neuer Seed aus der Systemzeit
+pub fn random_seed() -> u32 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    (Rng::new(nanos).next_u64() >> 32) as u32
+}
+
+// Seed-Code zum Teilen, z.B. "1A2B3C4D"
+pub fn format_seed_code(seed: u32) -> String {
+    format!("{:08X}", seed)
+}
+
+pub fn parse_seed_code(code: &str) -> Option<u32> {
+    u32::from_str_radix(code.trim(), 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(rng: &mut Rng) -> Vec<u64> {
+        (0..16).map(|_| rng.next_u64()).collect()
+    }
+
+    #[test]
+    fn same_seed_gives_same_sequence() {
+        assert_eq!(
+            sequence(&mut Rng::gameplay(1234)),
+            sequence(&mut Rng::gameplay(1234))
+        );
+        assert_ne!(
+            sequence(&mut Rng::gameplay(1234)),
+            sequence(&mut Rng::gameplay(1235))
+        );
+    }
+
+    #[test]
+    fn gameplay_and_cosmetic_streams_differ() {
+        assert_ne!(
+            sequence(&mut Rng::gameplay(1234)),
+            sequence(&mut Rng::cosmetic(1234))
+        );
+    }
+
+    #[test]
+    fn seed_code_round_trips() {
+        for seed in [0, 1, 0x1A2B_3C4D, u32::MAX] {
+            assert_eq!(parse_seed_code(&format_seed_code(seed)), Some(seed));
+        }
+        assert_eq!(parse_seed_code(" 1a2b3c4d "), Some(0x1A2B_3C4D));
+        assert_eq!(parse_seed_code("not a seed"), None);
+    }
+}
@@ -1,38 +1,73 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 
+use crate::difficulty::Difficulty;
+use crate::input::ControlScheme;
+use crate::weapon::WeaponType;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SaveData {
-    pub highscore: i32,
+    #[serde(default)]
+    pub highscores: BTreeMap<Difficulty, i32>, // Highscore pro Schwierigkeit
+    // Alter einzelner Highscore, wird beim Laden zu Normal übernommen
+    #[serde(default, rename = "highscore", skip_serializing)]
+    legacy_highscore: Option<i32>,
     pub settings: GameSettings,
     pub unlocked_skills: Vec<String>, // NEU: Gespeicherte Skills
+    #[serde(default)]
+    pub bosses_defeated: u32, // Besiegte Bosse über alle Runs
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GameSettings {
     pub music_volume: f32,
     pub sound_volume: f32,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    #[serde(default)]
+    pub adaptive_difficulty: bool, // Tempo passt sich der Leistung an
+    #[serde(default)]
+    pub loadout: WeaponType, // Startwaffe
+    #[serde(default)]
+    pub heat_enabled: bool, // Hitzemodell für die Kanonen
+    #[serde(default)]
+    pub control_scheme: ControlScheme, // Klassisch oder Maus-Zielen
 }
 
 impl Default for SaveData {
     fn default() -> Self {
         SaveData {
-            highscore: 0,
+            highscores: BTreeMap::new(),
+            legacy_highscore: None,
             settings: GameSettings {
                 music_volume: 0.7,
                 sound_volume: 0.8,
+                difficulty: Difficulty::Normal,
+                adaptive_difficulty: false,
+                loadout: WeaponType::Blaster,
+                heat_enabled: false,
+                control_scheme: ControlScheme::Classic,
             },
             unlocked_skills: Vec::new(), // NEU: Leere Skill-Liste
+            bosses_defeated: 0,
         }
     }
 }
 
 pub fn load_save() -> SaveData {
-    if let Ok(contents) = fs::read_to_string("savegame.json") {
+    let mut data = if let Ok(contents) = fs::read_to_string("savegame.json") {
         serde_json::from_str(&contents).unwrap_or_default()
     } else {
         SaveData::default()
+    };
+
+    // Alte Savegames: einzelner Highscore galt für Normal
+    if let Some(old) = data.legacy_highscore.take() {
+        let normal = data.highscores.entry(Difficulty::Normal).or_insert(0);
+        *normal = (*normal).max(old);
     }
+    data
 }
 
 pub fn save_game(data: &SaveData) {
@@ -43,10 +78,17 @@ pub fn save_game(data: &SaveData) {
 
 // ---- Update Funktionen ----
 
-// Highscore setzen
-pub fn update_highscore(new_score: i32) {
+// Highscore für eine Schwierigkeit setzen
+pub fn update_highscore(difficulty: Difficulty, new_score: i32) {
+    let mut data = load_save();
+    data.highscores.insert(difficulty, new_score);
+    save_game(&data);
+}
+
+// Besiegte Bosse eines Runs dazuzählen
+pub fn add_bosses_defeated(count: u32) {
     let mut data = load_save();
-    data.highscore = new_score;
+    data.bosses_defeated += count;
     save_game(&data);
 }
 
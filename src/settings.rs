@@ -1,4 +1,7 @@
+use crate::difficulty::Difficulty;
+use crate::input::ControlScheme;
 use crate::savegame::{GameSettings, load_save, update_settings};
+use crate::weapon::WeaponType;
 use macroquad::prelude::*;
 
 pub struct SettingsUI {
@@ -17,12 +20,63 @@ impl SettingsUI {
         }
     }
 
-    pub fn update_and_draw(&mut self) {
-        if !self.modal.is_open {
-            self.draw_main_ui();
-            self.handle_button_click();
-        }
+    pub fn is_open(&self) -> bool {
+        self.modal.is_open
+    }
+
+    pub fn difficulty(&self) -> Difficulty {
+        self.settings.difficulty
+    }
+
+    // Schwierigkeit im Menü wechseln und sofort speichern
+    pub fn cycle_difficulty(&mut self, step: i32) {
+        self.settings.difficulty = self.settings.difficulty.cycle(step);
+        update_settings(self.settings.clone());
+    }
+
+    pub fn adaptive(&self) -> bool {
+        self.settings.adaptive_difficulty
+    }
+
+    pub fn toggle_adaptive(&mut self) {
+        self.settings.adaptive_difficulty = !self.settings.adaptive_difficulty;
+        update_settings(self.settings.clone());
+    }
+
+    pub fn loadout(&self) -> WeaponType {
+        self.settings.loadout
+    }
+
+    pub fn cycle_loadout(&mut self) {
+        self.settings.loadout = self.settings.loadout.cycle(1);
+        update_settings(self.settings.clone());
+    }
+
+    pub fn heat(&self) -> bool {
+        self.settings.heat_enabled
+    }
+
+    pub fn toggle_heat(&mut self) {
+        self.settings.heat_enabled = !self.settings.heat_enabled;
+        update_settings(self.settings.clone());
+    }
 
+    pub fn control_scheme(&self) -> ControlScheme {
+        self.settings.control_scheme
+    }
+
+    pub fn toggle_control_scheme(&mut self) {
+        self.settings.control_scheme = self.settings.control_scheme.toggle();
+        update_settings(self.settings.clone());
+    }
+
+    // Modal mit aktuellem Stand öffnen (Working Copy)
+    pub fn open(&mut self) {
+        self.modal.open_with(self.settings.clone());
+    }
+
+    // Zeichnet nur das Modal; Save, Cancel und ESC schließen es
+    pub fn update_and_draw(&mut self) {
         if let Some(action) = self.modal.draw() {
             match action {
                 ModalAction::Save(new_settings) => {
@@ -45,15 +99,9 @@ impl SettingsUI {
         }
     }
 
-    fn draw_main_ui(&self) {
-        draw_text("Macroquad Audio Settings Demo", 20.0, 30.0, 24.0, BLACK);
-
-        // Button top-right, responsive
-        let button_w = (screen_width() * 0.15).clamp(120.0, 260.0);
-        let button_h = (screen_height() * 0.08).clamp(40.0, 72.0);
-        let button_x = screen_width() - button_w - 20.0;
-        let button_y = 20.0;
-        let button_rect = Rect::new(button_x, button_y, button_w, button_h);
+    // Settings-Button oben rechts und aktuelle Werte (für Title und Game Over)
+    pub fn draw_main_ui(&self) {
+        let button_rect = Self::button_rect();
 
         let mouse_pos = mouse_position();
         let hovered = button_rect.contains(Vec2::new(mouse_pos.0, mouse_pos.1));
@@ -76,19 +124,19 @@ impl SettingsUI {
         );
     }
 
-    fn handle_button_click(&mut self) {
+    pub fn button_clicked(&self) -> bool {
+        let mouse_pos = mouse_position();
+        let hovered = Self::button_rect().contains(Vec2::new(mouse_pos.0, mouse_pos.1));
+        hovered && is_mouse_button_pressed(MouseButton::Left)
+    }
+
+    // Button top-right, responsive
+    fn button_rect() -> Rect {
         let button_w = (screen_width() * 0.15).clamp(120.0, 260.0);
         let button_h = (screen_height() * 0.08).clamp(40.0, 72.0);
         let button_x = screen_width() - button_w - 20.0;
         let button_y = 20.0;
-        let button_rect = Rect::new(button_x, button_y, button_w, button_h);
-
-        let mouse_pos = mouse_position();
-        let hovered = button_rect.contains(Vec2::new(mouse_pos.0, mouse_pos.1));
-        if hovered && is_mouse_button_pressed(MouseButton::Left) {
-            // Modal mit aktuellem Stand öffnen (Working Copy)
-            self.modal.open_with(self.settings.clone());
-        }
+        Rect::new(button_x, button_y, button_w, button_h)
     }
 }
 
@@ -110,6 +158,11 @@ impl Modal {
             working: GameSettings {
                 music_volume: 0.7,
                 sound_volume: 0.8,
+                difficulty: Difficulty::Normal,
+                adaptive_difficulty: false,
+                loadout: WeaponType::Blaster,
+                heat_enabled: false,
+                control_scheme: ControlScheme::Classic,
             },
         }
     }
@@ -225,7 +278,7 @@ impl Modal {
 }
 
 // --- Helpers ---
-fn draw_button(rect: Rect, text: &str, hovered: bool) {
+pub fn draw_button(rect: Rect, text: &str, hovered: bool) {
     let base = if hovered {
         Color::new(0.85, 0.85, 0.85, 1.0)
     } else {
@@ -234,7 +287,7 @@ fn draw_button(rect: Rect, text: &str, hovered: bool) {
     draw_rectangle(rect.x, rect.y, rect.w, rect.h, base);
     draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, BLACK);
 
-    let text_size = (rect.h * 0.5).min(26.0).max(16.0);
+    let text_size = (rect.h * 0.5).clamp(16.0, 26.0);
     let text_dims = measure_text(text, None, text_size as u16, 1.0);
     draw_text(
         text,
@@ -79,11 +79,13 @@ pub enum SkillName {
 }
 
 // NEU: Hilfsfunktion um SkillName zu String zu konvertieren
-impl SkillName {
-    pub fn to_string(&self) -> String {
-        format!("{:?}", self)
+impl std::fmt::Display for SkillName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
     }
+}
 
+impl SkillName {
     pub fn from_string(s: &str) -> Option<SkillName> {
         match s {
             "RapidFire" => Some(SkillName::RapidFire),
@@ -147,12 +149,21 @@ impl SkillName {
     }
 }
 
+// Feste Reihenfolge, in der die Trees auf den Spieler angewendet werden
+const SKILL_TREE_ORDER: [SkillTreeType; 4] = [
+    SkillTreeType::Combat,
+    SkillTreeType::Survival,
+    SkillTreeType::Treasure,
+    SkillTreeType::Tech,
+];
+
 #[derive(Debug, Clone)]
 pub struct Skill {
     pub name: SkillName,
     pub max_points: u8,
     pub tier: u8,
     pub description: String,
+    #[allow(dead_code)]
     pub per_point_effect: String,
 }
 
@@ -176,11 +187,14 @@ impl SkillTree {
 
     // NEU: Skills aus Savegame laden
     pub fn load_from_save(&mut self) {
-        let unlocked_skills = load_unlocked_skills();
+        self.load_from_list(&load_unlocked_skills());
+    }
 
+    // Skills aus einer Liste im Savegame-Format ("SkillName:Points") laden
+    pub fn load_from_list(&mut self, unlocked_skills: &[String]) {
         for skill_string in unlocked_skills {
             if let Some(skill_name) =
-                SkillName::from_string(&skill_string.split(':').next().unwrap())
+                SkillName::from_string(skill_string.split(':').next().unwrap())
             {
                 // Prüfen ob der Skill zu diesem Tree gehört
                 let skills = Self::get_skills_for_tree(&self.tree_type);
@@ -197,10 +211,10 @@ impl SkillTree {
 
                     // Ultimates markieren
                     let skill = skills.iter().find(|s| s.name == skill_name);
-                    if let Some(skill) = skill {
-                        if skill.tier == 5 {
-                            self.selected_ultimate = Some(skill_name);
-                        }
+                    if let Some(skill) = skill
+                        && skill.tier == 5
+                    {
+                        self.selected_ultimate = Some(skill_name);
                     }
                 }
             }
@@ -229,8 +243,8 @@ impl SkillTree {
                     name: SkillName::PowerCapacitors,
                     max_points: 5,
                     tier: 1,
-                    description: "Faster reload speed".to_string(),
-                    per_point_effect: "+2% reload speed per point".to_string(),
+                    description: "Faster weapon cooling".to_string(),
+                    per_point_effect: "+2% cooling per point (weapon heat)".to_string(),
                 },
                 // Tier 2
                 Skill {
@@ -295,8 +309,8 @@ impl SkillTree {
                     name: SkillName::OverpressureCoolant,
                     max_points: 5,
                     tier: 4,
-                    description: "More damage but slower firing".to_string(),
-                    per_point_effect: "+2% damage, +1% cooldown per point".to_string(),
+                    description: "More damage but slower firing, less heat".to_string(),
+                    per_point_effect: "+2% damage, +1% cooldown, -5% heat per point".to_string(),
                 },
                 // Tier 5 (Ultimates)
                 Skill {
@@ -677,14 +691,14 @@ impl SkillTree {
 
             // If it's an ultimate, mark it as selected
             let skills = Self::get_skills_for_tree(&self.tree_type);
-            if let Some(skill) = skills.iter().find(|s| s.name == skill_name) {
-                if skill.tier == 5 {
-                    self.selected_ultimate = Some(skill_name.clone());
-                }
+            if let Some(skill) = skills.iter().find(|s| s.name == skill_name)
+                && skill.tier == 5
+            {
+                self.selected_ultimate = Some(skill_name.clone());
             }
 
             // NEU: Skill im Savegame speichern
-            let skill_key = format!("{}:{}", skill_name.to_string(), current_points + 1);
+            let skill_key = format!("{}:{}", skill_name, current_points + 1);
             unlock_skill(&skill_key);
 
             true
@@ -694,9 +708,11 @@ impl SkillTree {
     }
 
     pub fn apply_skills_to_player(&self, player: &mut Player) {
-        // Apply individual skills
-        for (skill_name, points) in &self.allocated_points {
-            self.apply_skill_effect(skill_name, *points, player);
+        // Apply individual skills in tree order so the result is identical every run (replays)
+        for skill in Self::get_skills_for_tree(&self.tree_type) {
+            if let Some(points) = self.allocated_points.get(&skill.name) {
+                self.apply_skill_effect(&skill.name, *points, player);
+            }
         }
     }
 
@@ -713,7 +729,7 @@ impl SkillTree {
                 player.damage_multiplier *= 1.0 + (0.04 * points_f);
             }
             SkillName::PowerCapacitors => {
-                // This would affect reload animation speed if implemented
+                // Weapon heat cools down faster (only with the heat model)
                 player.reload_speed_multiplier = 1.0 + (0.02 * points_f);
             }
             SkillName::PiercingShots => {
@@ -744,6 +760,7 @@ impl SkillTree {
                 player.damage_multiplier *= 1.0 + (0.02 * points_f);
                 player.base_shoot_cooldown *= 1.0 + (0.01 * points_f);
                 player.max_shoot_ccooldown = player.base_shoot_cooldown;
+                player.heat_gain_multiplier *= 1.0 - (0.05 * points_f);
             }
 
             // Survival Skills
@@ -862,6 +879,12 @@ impl SkillTree {
             SkillName::EnergyOverflow => {
                 player.energy_overflow_bonus = 0.05 * points_f;
             }
+            SkillName::GoldenTouch => {
+                player.golden_touch = true;
+            }
+            SkillName::PlasmaCannon => {
+                player.plasma_cannon = true;
+            }
 
             // Ultimates are handled separately as active abilities
             _ => {}
@@ -921,25 +944,51 @@ impl SkillTreeManager {
     }
 
     pub fn spend_skill_point(&mut self, skill_name: SkillName) -> bool {
-        if self.available_skill_points > 0 {
-            if let Some(tree) = self.skill_trees.get_mut(&self.active_tab) {
-                if tree.allocate_point(skill_name) {
-                    self.available_skill_points -= 1;
-                    return true;
-                }
-            }
+        if self.available_skill_points > 0
+            && let Some(tree) = self.skill_trees.get_mut(&self.active_tab)
+            && tree.allocate_point(skill_name)
+        {
+            self.available_skill_points -= 1;
+            return true;
         }
         false
     }
 
     pub fn apply_to_player(&self, player: &mut Player) {
-        for tree in self.skill_trees.values() {
+        for tree_type in SKILL_TREE_ORDER.iter() {
+            if let Some(tree) = self.skill_trees.get(tree_type) {
+                tree.apply_skills_to_player(player);
+            }
+        }
+    }
+
+    // Aktuell verteilte Punkte im Savegame-Format ("SkillName:Points")
+    pub fn skill_list(&self) -> Vec<String> {
+        let mut list = Vec::new();
+        for tree_type in SKILL_TREE_ORDER.iter() {
+            if let Some(tree) = self.skill_trees.get(tree_type) {
+                for skill in SkillTree::get_skills_for_tree(tree_type) {
+                    if let Some(points) = tree.allocated_points.get(&skill.name) {
+                        list.push(format!("{}:{}", skill.name, points));
+                    }
+                }
+            }
+        }
+        list
+    }
+
+    // Skills aus einer gespeicherten Liste anwenden (z.B. für Replays)
+    pub fn apply_skill_list(unlocked_skills: &[String], player: &mut Player) {
+        for tree_type in SKILL_TREE_ORDER.iter() {
+            let mut tree = SkillTree::new(tree_type.clone());
+            tree.load_from_list(unlocked_skills);
             tree.apply_skills_to_player(player);
         }
     }
 
-    pub fn calculate_skill_points_from_score(score: i32) -> u8 {
-        (score / 1000) as u8 // 1 skill point per 1000 score
+    pub fn calculate_skill_points(score: i32, bosses_defeated: u32) -> u8 {
+        // 1 skill point per 1000 score, 2 per boss defeated
+        (score / 1000 + bosses_defeated as i32 * 2).min(u8::MAX as i32) as u8
     }
 
     pub fn draw_and_handle_input(&mut self) {
@@ -985,7 +1034,7 @@ impl SkillTreeManager {
         self.draw_active_tree_skills();
 
         // Anweisungen
-        let instruction = "Press T to close | Click on skills to allocate points";
+        let instruction = "Press T or ESC to close | Click on skills to allocate points";
         let inst_size = measure_text(instruction, None, small_font as u16, 1.0);
         draw_text(
             instruction,
@@ -1051,7 +1100,7 @@ impl SkillTreeManager {
         // Skills nach Tiers gruppieren
         let mut tiers: HashMap<u8, Vec<&Skill>> = HashMap::new();
         for skill in &skills {
-            tiers.entry(skill.tier).or_insert_with(Vec::new).push(skill);
+            tiers.entry(skill.tier).or_default().push(skill);
         }
 
         // Berechne Größen basierend auf verfügbarem Platz
@@ -1074,15 +1123,11 @@ impl SkillTreeManager {
         for tier in 1..=5 {
             if let Some(tier_skills) = tiers.get(&tier) {
                 // Tier-Titel
-                let tier_title = format!(
-                    "Tier {} ({})",
-                    tier,
-                    if tier == 5 {
-                        "Ultimates"
-                    } else {
-                        &format!("{} points required", (tier - 1) * 5)
-                    }
-                );
+                let tier_title = if tier == 5 {
+                    format!("Tier {} (Ultimates)", tier)
+                } else {
+                    format!("Tier {} ({} points required)", tier, (tier - 1) * 5)
+                };
                 let title_font_size = (tier_title_height * 0.6).max(12.0);
                 draw_text(
                     &tier_title,
@@ -0,0 +1,326 @@
+use macroquad::prelude::*;
+
+use crate::world::Arena;
+
+// Gleichmäßiges Raster über dem Spielfeld für schnelle Nachbarschaftsabfragen.
+// Gespeichert werden nur Indizes in die jeweilige Entity-Liste, daher muss das
+// Raster neu gebaut werden, sobald sich Positionen oder die Liste ändern.
+// Objekte außerhalb des Spielfelds landen in den Randzellen.
+pub struct SpatialGrid {
+    cell_size: f32,
+    cols: usize,
+    rows: usize,
+    cells: Vec<Vec<usize>>,
+    // Markiert bereits gefundene Indizes, damit Objekte in mehreren Zellen
+    // nur einmal zurückgegeben werden
+    seen: Vec<bool>,
+}
+
+impl SpatialGrid {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cols: 0,
+            rows: 0,
+            cells: Vec::new(),
+            seen: Vec::new(),
+        }
+    }
+
+    // Raster mit (x, y, radius) je Entity neu aufbauen; der Index ist die Position im Iterator
+    pub fn rebuild(&mut self, arena: Arena, entries: impl Iterator<Item = (f32, f32, f32)>) {
+        let cols = (arena.width / self.cell_size).ceil().max(1.0) as usize;
+        let rows = (arena.height / self.cell_size).ceil().max(1.0) as usize;
+        if cols != self.cols || rows != self.rows {
+            self.cols = cols;
+            self.rows = rows;
+            self.cells = vec![Vec::new(); cols * rows];
+        } else {
+            for cell in self.cells.iter_mut() {
+                cell.clear();
+            }
+        }
+
+        let mut count = 0;
+        for (index, (x, y, radius)) in entries.enumerate() {
+            let (min_col, min_row) = self.cell_of(x - radius, y - radius);
+            let (max_col, max_row) = self.cell_of(x + radius, y + radius);
+            for row in min_row..=max_row {
+                for col in min_col..=max_col {
+                    self.cells[row * self.cols + col].push(index);
+                }
+            }
+            count = index + 1;
+        }
+        self.seen.clear();
+        self.seen.resize(count, false);
+    }
+
+    // Alle Indizes, deren Zellen den Kreis berühren (aufsteigend sortiert).
+    // Die genaue Abstandsprüfung bleibt beim Aufrufer.
+    pub fn query_circle(&mut self, x: f32, y: f32, radius: f32, out: &mut Vec<usize>) {
+        out.clear();
+        if self.cells.is_empty() {
+            return;
+        }
+
+        let (min_col, min_row) = self.cell_of(x - radius, y - radius);
+        let (max_col, max_row) = self.cell_of(x + radius, y + radius);
+        for row in min_row..=max_row {
+            for col in min_col..=max_col {
+                self.collect_cell(col, row, out);
+            }
+        }
+        self.finish_query(out);
+    }
+
+    // Alle Indizes in den Zellen, die der Strahl von origin aus auf `length` durchläuft
+    // (Zelle für Zelle, aufsteigend sortiert). dir muss normiert sein.
+    pub fn query_ray(&mut self, origin: Vec2, dir: Vec2, length: f32, out: &mut Vec<usize>) {
+        out.clear();
+        if self.cells.is_empty() {
+            return;
+        }
+
+        let (mut col, mut row) = self.cell_of(origin.x, origin.y);
+        let step_col = if dir.x > 0.0 { 1 } else { -1 };
+        let step_row = if dir.y > 0.0 { 1 } else { -1 };
+        // Strahlparameter bis zur nächsten Zellgrenze und pro ganzer Zelle
+        let boundary = |cell: usize, step: isize, start: f32, d: f32| {
+            if d == 0.0 {
+                return f32::INFINITY;
+            }
+            let edge = (cell as f32 + if step > 0 { 1.0 } else { 0.0 }) * self.cell_size;
+            (edge - start) / d
+        };
+        let mut t_col = boundary(col, step_col, origin.x, dir.x);
+        let mut t_row = boundary(row, step_row, origin.y, dir.y);
+        let delta_col = self.cell_size / dir.x.abs();
+        let delta_row = self.cell_size / dir.y.abs();
+
+        loop {
+            self.collect_cell(col, row, out);
+            if t_col.min(t_row) > length {
+                break;
+            }
+            if t_col < t_row {
+                match col.checked_add_signed(step_col) {
+                    Some(next) if next < self.cols => col = next,
+                    _ => break,
+                }
+                t_col += delta_col;
+            } else {
+                match row.checked_add_signed(step_row) {
+                    Some(next) if next < self.rows => row = next,
+                    _ => break,
+                }
+                t_row += delta_row;
+            }
+        }
+        self.finish_query(out);
+    }
+
+    fn collect_cell(&mut self, col: usize, row: usize, out: &mut Vec<usize>) {
+        for &index in &self.cells[row * self.cols + col] {
+            if !self.seen[index] {
+                self.seen[index] = true;
+                out.push(index);
+            }
+        }
+    }
+
+    fn finish_query(&mut self, out: &mut [usize]) {
+        for &index in out.iter() {
+            self.seen[index] = false;
+        }
+
+        // Gleiche Reihenfolge wie eine Schleife über die ganze Liste
+        out.sort_unstable();
+    }
+
+    // Nächstgelegenes Objekt zum Punkt. distance(index) liefert den genauen Abstand.
+    // Bei gleichem Abstand gewinnt der kleinere Index, wie bei einer linearen Suche.
+    pub fn nearest(&self, x: f32, y: f32, distance: impl Fn(usize) -> f32) -> Option<usize> {
+        if self.cells.is_empty() {
+            return None;
+        }
+
+        let (center_col, center_row) = self.cell_of(x, y);
+        let max_ring = self.cols.max(self.rows);
+        let mut best: Option<(f32, usize)> = None;
+
+        for ring in 0..=max_ring {
+            let min_col = center_col.saturating_sub(ring);
+            let max_col = (center_col + ring).min(self.cols - 1);
+            let min_row = center_row.saturating_sub(ring);
+            let max_row = (center_row + ring).min(self.rows - 1);
+
+            for row in min_row..=max_row {
+                for col in min_col..=max_col {
+                    // Nur den äußeren Ring besuchen, das Innere ist schon geprüft
+                    let on_ring =
+                        row.abs_diff(center_row) == ring || col.abs_diff(center_col) == ring;
+                    if !on_ring {
+                        continue;
+                    }
+                    for &index in &self.cells[row * self.cols + col] {
+                        let d = distance(index);
+                        let better = match best {
+                            Some((best_d, best_index)) => {
+                                d < best_d || (d == best_d && index < best_index)
+                            }
+                            None => true,
+                        };
+                        if better {
+                            best = Some((d, index));
+                        }
+                    }
+                }
+            }
+
+            // Unbesuchte Zellen liegen mindestens so weit entfernt wie der nächste noch
+            // offene Rand des besuchten Blocks. Gemessen vom echten Punkt, nicht von
+            // der geklemmten Zelle, damit es auch außerhalb des Spielfelds stimmt.
+            let open_edges = [
+                (min_col > 0).then_some(x - min_col as f32 * self.cell_size),
+                (max_col + 1 < self.cols).then_some((max_col + 1) as f32 * self.cell_size - x),
+                (min_row > 0).then_some(y - min_row as f32 * self.cell_size),
+                (max_row + 1 < self.rows).then_some((max_row + 1) as f32 * self.cell_size - y),
+            ];
+            let Some(reach) = open_edges.into_iter().flatten().reduce(f32::min) else {
+                break; // Alle Zellen besucht
+            };
+            if let Some((best_d, _)) = best
+                && best_d <= reach
+            {
+                break;
+            }
+        }
+
+        best.map(|(_, index)| index)
+    }
+
+    fn cell_of(&self, x: f32, y: f32) -> (usize, usize) {
+        let col = ((x / self.cell_size).floor().max(0.0) as usize).min(self.cols - 1);
+        let row = ((y / self.cell_size).floor().max(0.0) as usize).min(self.rows - 1);
+        (col, row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    fn arena() -> Arena {
+        Arena::new(1920.0, 1080.0)
+    }
+
+    fn grid_with(entries: &[(f32, f32, f32)]) -> SpatialGrid {
+        let mut grid = SpatialGrid::new(128.0);
+        grid.rebuild(arena(), entries.iter().copied());
+        grid
+    }
+
+    #[test]
+    fn query_circle_returns_each_index_once() {
+        // Index 1 überspannt viele Zellen, Index 2 liegt weit weg
+        let grid_entries = [
+            (100.0, 100.0, 10.0),
+            (300.0, 300.0, 250.0),
+            (1800.0, 1000.0, 10.0),
+        ];
+        let mut grid = grid_with(&grid_entries);
+        let mut out = Vec::new();
+        grid.query_circle(200.0, 200.0, 300.0, &mut out);
+        assert_eq!(out, vec![0, 1]);
+
+        // Zweite Abfrage liefert dasselbe, die Markierungen wurden zurückgesetzt
+        grid.query_circle(200.0, 200.0, 300.0, &mut out);
+        assert_eq!(out, vec![0, 1]);
+    }
+
+    #[test]
+    fn nearest_matches_linear_search() {
+        let mut rng = Rng::new(3);
+        let points: Vec<(f32, f32, f32)> = (0..200)
+            .map(|_| {
+                (
+                    rng.gen_range(-200.0, 2120.0),
+                    rng.gen_range(-200.0, 1280.0),
+                    0.0,
+                )
+            })
+            .collect();
+        let grid = grid_with(&points);
+
+        // Abfragen im Spielfeld und weit außerhalb
+        for _ in 0..500 {
+            let x = rng.gen_range(-1500.0, 3400.0);
+            let y = rng.gen_range(-1500.0, 2600.0);
+            let distance = |i: usize| {
+                let (px, py, _) = points[i];
+                ((px - x).powi(2) + (py - y).powi(2)).sqrt()
+            };
+            let linear = (0..points.len()).min_by(|&a, &b| distance(a).total_cmp(&distance(b)));
+            assert_eq!(grid.nearest(x, y, distance), linear, "query ({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn query_ray_follows_the_line() {
+        let entries = [
+            (300.0, 500.0, 10.0),  // auf dem Strahl
+            (900.0, 520.0, 10.0),  // auf dem Strahl
+            (1500.0, 500.0, 10.0), // hinter dem Ende
+            (300.0, 100.0, 10.0),  // daneben
+        ];
+        let mut grid = grid_with(&entries);
+        let mut out = Vec::new();
+        grid.query_ray(Vec2::new(100.0, 500.0), Vec2::X, 1000.0, &mut out);
+        assert_eq!(out, vec![0, 1]);
+
+        // Rückwärts vom Ende aus
+        grid.query_ray(Vec2::new(1600.0, 500.0), -Vec2::X, 1000.0, &mut out);
+        assert_eq!(out, vec![1, 2]);
+    }
+
+    #[test]
+    fn query_ray_finds_every_circle_on_the_segment() {
+        let mut rng = Rng::new(5);
+        let entries: Vec<(f32, f32, f32)> = (0..300)
+            .map(|_| {
+                (
+                    rng.gen_range(0.0, 1920.0),
+                    rng.gen_range(0.0, 1080.0),
+                    rng.gen_range(5.0, 60.0),
+                )
+            })
+            .collect();
+        let mut grid = grid_with(&entries);
+        let mut out = Vec::new();
+
+        for _ in 0..200 {
+            let origin = Vec2::new(rng.gen_range(0.0, 1920.0), rng.gen_range(0.0, 1080.0));
+            let dir = Vec2::from_angle(rng.gen_range(0.0, std::f32::consts::TAU));
+            let length = rng.gen_range(50.0, 1500.0);
+            grid.query_ray(origin, dir, length, &mut out);
+
+            // Jeder Kreis, den die Strecke berührt, muss unter den Kandidaten sein
+            for (i, &(x, y, radius)) in entries.iter().enumerate() {
+                let center = Vec2::new(x, y);
+                let t = (center - origin).dot(dir).clamp(0.0, length);
+                if center.distance(origin + dir * t) <= radius {
+                    assert!(out.contains(&i), "{i} missing for ray from {origin}");
+                }
+            }
+            assert!(out.is_sorted());
+        }
+    }
+
+    #[test]
+    fn nearest_on_empty_grid_is_none() {
+        let grid = grid_with(&[]);
+        assert_eq!(grid.nearest(10.0, 10.0, |_| 0.0), None);
+    }
+}
@@ -1,37 +1,46 @@
+use crate::help_fn::lerp;
+use crate::rng::Rng;
+use crate::world::Arena;
 use macroquad::prelude::*;
-use rand::gen_range;
 
 pub struct Star {
     pub x: f32,
     pub y: f32,
+    pub prev_y: f32, // Position des vorherigen Ticks (für Interpolation)
     pub size: f32,
     pub speed: f32,
     pub base_brightness: f32,
 }
 
 impl Star {
-    pub fn new() -> Self {
+    pub fn new(arena: Arena, rng: &mut Rng) -> Self {
+        let y = rng.gen_range(0.0, arena.height);
+
         Star {
-            x: gen_range(0.0, screen_width()),
-            y: gen_range(0.0, screen_height()),
-            size: gen_range(0.5, 2.5),
-            speed: gen_range(10.0, 60.0),
-            base_brightness: gen_range(0.5, 1.0),
+            x: rng.gen_range(0.0, arena.width),
+            y,
+            prev_y: y,
+            size: rng.gen_range(0.5, 2.5),
+            speed: rng.gen_range(10.0, 60.0),
+            base_brightness: rng.gen_range(0.5, 1.0),
         }
     }
 
-    pub fn update(&mut self) {
-        self.y += self.speed * get_frame_time();
+    pub fn update(&mut self, dt: f32, arena: Arena, rng: &mut Rng) {
+        self.prev_y = self.y;
+        self.y += self.speed * dt;
 
         // Wenn der Stern unten den Bildschirm verlässt, respawne ihn oben
-        if self.y > screen_height() + self.size {
-            self.x = gen_range(0.0, screen_width());
+        if self.y > arena.height + self.size {
+            self.x = rng.gen_range(0.0, arena.width);
             self.y = -self.size;
+            self.prev_y = self.y; // kein Schlieren beim Respawn
         }
     }
 
-    pub fn draw(&self, index: usize) {
+    pub fn draw(&self, index: usize, alpha: f32) {
         let time = get_time() as f32;
+        let y = lerp(self.prev_y, self.y, alpha);
 
         // Twinkle-Effekt (Sternenflimmern)
         let twinkle = (time * (2.0 + (index as f32 % 5.0)) + self.x * 0.1).sin() * 0.3 + 0.7;
@@ -39,7 +48,7 @@ impl Star {
 
         draw_circle(
             self.x,
-            self.y,
+            y,
             self.size,
             Color::new(brightness, brightness, brightness, 0.9),
         );
@@ -0,0 +1,393 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+use crate::debris_kind::DebrisKind;
+use crate::difficulty::DifficultyPreset;
+use crate::items::ItemType;
+
+pub const WAVES_FILE: &str = "assets/waves.json";
+
+// Anordnung einer gescripteten Debris-Gruppe
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Formation {
+    Random, // Einzeln von zufälligen Seiten, wie der normale Spawn
+    Line,   // Nebeneinander an einer Seite
+    Wedge,  // Keil, die Mitte fliegt voraus
+}
+
+// Seite, von der eine Formation einfliegt
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+// Gescripteter Spawn innerhalb einer Welle. `at` und `every` in Sekunden ab Wellenstart.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SpawnEntry {
+    pub at: f32,
+    #[serde(default)]
+    pub kind: Option<String>, // Name aus debris_kinds.json, sonst zufällig
+    #[serde(default = "default_count")]
+    pub count: u32,
+    #[serde(default = "default_formation")]
+    pub formation: Formation,
+    #[serde(default = "default_side")]
+    pub side: Side,
+    #[serde(default = "default_count")]
+    pub repeat: u32,
+    #[serde(default)]
+    pub every: f32,
+}
+
+fn default_count() -> u32 {
+    1
+}
+
+fn default_formation() -> Formation {
+    Formation::Random
+}
+
+fn default_side() -> Side {
+    Side::Top
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ItemDrop {
+    pub at: f32,
+    pub item: ItemType,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Wave {
+    pub name: String,
+    pub duration: f32,
+    #[serde(default)]
+    pub rest: f32, // Pause nach der Welle ohne Spawns
+    pub background_spawn_rate: Option<f32>, // Sekunden zwischen zufälligen Spawns, None = aus
+    #[serde(default)]
+    pub spawns: Vec<SpawnEntry>,
+    #[serde(default)]
+    pub items: Vec<ItemDrop>,
+}
+
+// Ablauf eines Runs. Nach der letzten Welle geht es von vorne los,
+// der Hintergrund-Spawn wird pro Durchlauf schneller.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StageScript {
+    pub waves: Vec<Wave>,
+    #[serde(default = "default_loop_speedup")]
+    pub loop_speedup: f32,
+    #[serde(default = "default_min_spawn_rate")]
+    pub min_spawn_rate: f32,
+}
+
+fn default_loop_speedup() -> f32 {
+    0.9
+}
+
+fn default_min_spawn_rate() -> f32 {
+    0.2
+}
+
+impl StageScript {
+    // Eingebauter Ablauf, entspricht der alten Regel: alle 10 Sekunden 10% schneller
+    pub fn endless() -> Self {
+        Self {
+            waves: vec![Wave {
+                name: "Endless".to_string(),
+                duration: 10.0,
+                rest: 0.0,
+                background_spawn_rate: Some(1.0),
+                spawns: Vec::new(),
+                items: Vec::new(),
+            }],
+            loop_speedup: default_loop_speedup(),
+            min_spawn_rate: default_min_spawn_rate(),
+        }
+    }
+
+    // Tempo an die Schwierigkeit anpassen
+    pub fn apply_difficulty(&mut self, preset: &DifficultyPreset) {
+        for wave in self.waves.iter_mut() {
+            if let Some(rate) = wave.background_spawn_rate.as_mut() {
+                *rate *= preset.spawn_rate_scale;
+            }
+        }
+        self.loop_speedup = self.loop_speedup.powf(preset.decay_strength);
+        self.min_spawn_rate *= preset.min_spawn_rate_scale;
+    }
+
+    // Ungültige Werte aus der Config abfangen
+    fn is_valid(&self, kinds: &[DebrisKind]) -> bool {
+        !self.waves.is_empty()
+            && self.waves.iter().all(|w| w.is_valid(kinds))
+            && self.loop_speedup > 0.0
+            && self.min_spawn_rate > 0.0
+    }
+}
+
+impl Wave {
+    // Alle Spawns und Items müssen innerhalb der Welle liegen, sonst würden sie
+    // nie ausgelöst. Namen müssen in debris_kinds.json existieren.
+    fn is_valid(&self, kinds: &[DebrisKind]) -> bool {
+        self.duration > 0.0
+            && self.rest >= 0.0
+            && self.background_spawn_rate.is_none_or(|rate| rate > 0.0)
+            && self.spawns.iter().all(|entry| {
+                entry.at >= 0.0
+                    && entry.every >= 0.0
+                    && entry.repeat > 0
+                    && entry.at + entry.every * ((entry.repeat - 1) as f32) < self.duration
+                    && entry
+                        .kind
+                        .as_ref()
+                        .is_none_or(|name| kinds.iter().any(|k| &k.name == name))
+            })
+            && self
+                .items
+                .iter()
+                .all(|drop| drop.at >= 0.0 && drop.at < self.duration)
+    }
+}
+
+// Ablauf aus der Config laden, bei Fehlern der eingebaute Standard
+pub fn load_stage_script(kinds: &[DebrisKind]) -> StageScript {
+    fs::read_to_string(WAVES_FILE)
+        .ok()
+        .and_then(|contents| serde_json::from_str::<StageScript>(&contents).ok())
+        .filter(|script| script.is_valid(kinds))
+        .unwrap_or_else(StageScript::endless)
+}
+
+// Was der Director in diesem Tick auslösen möchte, World führt es aus
+#[derive(Debug, Clone)]
+pub enum WaveAction {
+    WaveStarted { number: u32 },
+    SpawnGroup(SpawnEntry),
+    DropItem(ItemType),
+}
+
+// Spielt den StageScript ab und bestimmt das Tempo des Runs
+pub struct WaveDirector {
+    pub script: StageScript,
+    pub wave_number: u32, // Angezeigte Wellennummer, zählt über Durchläufe weiter
+    pub wave_index: usize,
+    pub loop_count: u32,
+    pub timer: f32,
+    pub resting: bool,
+    // Ausstehende Aktionen der aktuellen Welle, nach Zeit sortiert
+    schedule: Vec<(f32, WaveAction)>,
+    next_action: usize,
+}
+
+impl WaveDirector {
+    pub fn new(script: StageScript) -> Self {
+        let mut director = Self {
+            script,
+            wave_number: 0,
+            wave_index: 0,
+            loop_count: 0,
+            timer: 0.0,
+            resting: false,
+            schedule: Vec::new(),
+            next_action: 0,
+        };
+        director.start_wave();
+        director
+    }
+
+    pub fn current_wave(&self) -> &Wave {
+        &self.script.waves[self.wave_index]
+    }
+
+    // Sekunden zwischen zufälligen Spawns, None während Pausen oder wenn die Welle keine hat
+    pub fn spawn_rate(&self) -> Option<f32> {
+        if self.resting {
+            return None;
+        }
+        self.current_wave().background_spawn_rate.map(|rate| {
+            (rate * self.script.loop_speedup.powi(self.loop_count as i32))
+                .max(self.script.min_spawn_rate)
+        })
+    }
+
+    fn start_wave(&mut self) {
+        self.wave_number += 1;
+        self.timer = 0.0;
+        self.resting = false;
+        self.next_action = 0;
+
+        let wave = &self.script.waves[self.wave_index];
+        self.schedule.clear();
+        self.schedule.push((
+            0.0,
+            WaveAction::WaveStarted {
+                number: self.wave_number,
+            },
+        ));
+        for entry in &wave.spawns {
+            for i in 0..entry.repeat {
+                self.schedule.push((
+                    entry.at + entry.every * i as f32,
+                    WaveAction::SpawnGroup(entry.clone()),
+                ));
+            }
+        }
+        for drop in &wave.items {
+            self.schedule
+                .push((drop.at, WaveAction::DropItem(drop.item.clone())));
+        }
+        // Stabil sortiert, damit gleichzeitige Einträge in Dateireihenfolge bleiben
+        self.schedule.sort_by(|a, b| a.0.total_cmp(&b.0));
+    }
+
+    pub fn update(&mut self, dt: f32, actions: &mut Vec<WaveAction>) {
+        self.timer += dt;
+
+        while let Some((at, action)) = self.schedule.get(self.next_action)
+            && *at <= self.timer
+            && !self.resting
+        {
+            actions.push(action.clone());
+            self.next_action += 1;
+        }
+
+        let wave = self.current_wave();
+        if !self.resting && self.timer >= wave.duration {
+            self.resting = true;
+            self.timer = 0.0;
+        }
+
+        if self.resting && self.timer >= self.current_wave().rest {
+            // Nächste Welle, nach der letzten wieder von vorne und schneller
+            self.wave_index += 1;
+            if self.wave_index >= self.script.waves.len() {
+                self.wave_index = 0;
+                self.loop_count += 1;
+            }
+            self.start_wave();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::debris_kind::load_debris_kinds;
+
+    fn spawn(at: f32, repeat: u32, every: f32) -> SpawnEntry {
+        SpawnEntry {
+            at,
+            kind: None,
+            count: 1,
+            formation: Formation::Random,
+            side: Side::Top,
+            repeat,
+            every,
+        }
+    }
+
+    fn wave(duration: f32, rest: f32, spawns: Vec<SpawnEntry>) -> Wave {
+        Wave {
+            name: "Test".to_string(),
+            duration,
+            rest,
+            background_spawn_rate: Some(1.0),
+            spawns,
+            items: Vec::new(),
+        }
+    }
+
+    fn script(waves: Vec<Wave>) -> StageScript {
+        StageScript {
+            waves,
+            loop_speedup: 0.5,
+            min_spawn_rate: 0.2,
+        }
+    }
+
+    // Director in festen Schritten laufen lassen, liefert (Zeitpunkt, Aktion)
+    fn run(director: &mut WaveDirector, seconds: f32) -> Vec<(f32, WaveAction)> {
+        let dt = 0.1;
+        let mut fired = Vec::new();
+        for step in 1..=(seconds / dt).round() as u32 {
+            let mut actions = Vec::new();
+            director.update(dt, &mut actions);
+            fired.extend(actions.into_iter().map(|a| (step as f32 * dt, a)));
+        }
+        fired
+    }
+
+    #[test]
+    fn shipped_script_is_valid() {
+        let kinds = load_debris_kinds();
+        let script: StageScript =
+            serde_json::from_str(&fs::read_to_string(WAVES_FILE).unwrap()).unwrap();
+        assert!(script.is_valid(&kinds));
+    }
+
+    #[test]
+    fn spawns_outside_the_wave_or_with_unknown_kinds_are_rejected() {
+        let kinds = vec![DebrisKind::scrap()];
+        assert!(script(vec![wave(10.0, 0.0, vec![spawn(2.0, 3, 3.0)])]).is_valid(&kinds));
+        // Letzte Wiederholung bei 2 + 3 * 3 = 11 Sekunden, Welle endet bei 10
+        assert!(!script(vec![wave(10.0, 0.0, vec![spawn(2.0, 4, 3.0)])]).is_valid(&kinds));
+        assert!(!script(vec![wave(10.0, 0.0, vec![spawn(10.0, 1, 0.0)])]).is_valid(&kinds));
+
+        let mut named = spawn(1.0, 1, 0.0);
+        named.kind = Some("scrap".to_string());
+        assert!(script(vec![wave(10.0, 0.0, vec![named.clone()])]).is_valid(&kinds));
+        named.kind = Some("unknown".to_string());
+        assert!(!script(vec![wave(10.0, 0.0, vec![named])]).is_valid(&kinds));
+    }
+
+    #[test]
+    fn director_fires_spawns_on_schedule() {
+        let mut director =
+            WaveDirector::new(script(vec![wave(10.0, 2.0, vec![spawn(1.0, 3, 2.0)])]));
+        let fired = run(&mut director, 10.0);
+
+        assert!(matches!(fired[0].1, WaveAction::WaveStarted { number: 1 }));
+        let spawn_times: Vec<f32> = fired
+            .iter()
+            .filter(|(_, a)| matches!(a, WaveAction::SpawnGroup(_)))
+            .map(|(t, _)| *t)
+            .collect();
+        assert_eq!(spawn_times.len(), 3);
+        for (t, expected) in spawn_times.iter().zip([1.0, 3.0, 5.0]) {
+            assert!((t - expected).abs() < 0.15, "{t} != {expected}");
+        }
+        assert!(director.resting);
+        assert_eq!(director.spawn_rate(), None);
+    }
+
+    #[test]
+    fn director_rests_then_loops_faster() {
+        let mut director = WaveDirector::new(script(vec![
+            wave(5.0, 1.0, Vec::new()),
+            wave(5.0, 1.0, Vec::new()),
+        ]));
+        assert_eq!(director.spawn_rate(), Some(1.0));
+
+        // Während der Pause passiert nichts
+        let fired = run(&mut director, 5.5);
+        assert_eq!(fired.len(), 1);
+
+        // Zweite Welle, danach wieder die erste im nächsten Durchlauf
+        let fired = run(&mut director, 11.0);
+        let numbers: Vec<u32> = fired
+            .iter()
+            .filter_map(|(_, a)| match a {
+                WaveAction::WaveStarted { number } => Some(*number),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(numbers, vec![2, 3]);
+        assert_eq!(director.wave_index, 0);
+        assert_eq!(director.loop_count, 1);
+        assert_eq!(director.spawn_rate(), Some(0.5));
+    }
+}
@@ -0,0 +1,248 @@
+use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::bullet::{Bullet, BulletStyle};
+use crate::player::Player;
+use crate::rng::Rng;
+use crate::world::Arena;
+
+// Streuung des zusätzlichen Paars durch TwinCannons (double_shot_chance)
+const DOUBLE_SHOT_SPREAD: f32 = 0.2;
+
+// Wie lange eine Waffen-Kiste liegen bleibt
+const PICKUP_LIFETIME: f32 = 12.0;
+
+// Bewaffnung des Spielers. Jede Waffe bestimmt Feuerrate, Schussmuster und
+// wie ein einzelnes Projektil aussieht; die Combat-Skills kommen immer obendrauf.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WeaponType {
+    #[default]
+    Blaster,
+    SpreadShot,
+    RapidPulse,
+    HomingMissiles,
+    Railgun,
+    Beam,
+}
+
+impl WeaponType {
+    pub const ALL: [WeaponType; 6] = [
+        WeaponType::Blaster,
+        WeaponType::SpreadShot,
+        WeaponType::RapidPulse,
+        WeaponType::HomingMissiles,
+        WeaponType::Railgun,
+        WeaponType::Beam,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            WeaponType::Blaster => "Blaster",
+            WeaponType::SpreadShot => "Spread Shot",
+            WeaponType::RapidPulse => "Rapid Pulse",
+            WeaponType::HomingMissiles => "Homing Missiles",
+            WeaponType::Railgun => "Railgun",
+            WeaponType::Beam => "Laser Beam",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            WeaponType::Blaster => Color::new(0.5, 0.9, 1.0, 1.0),
+            WeaponType::SpreadShot => Color::new(1.0, 0.9, 0.3, 1.0),
+            WeaponType::RapidPulse => Color::new(0.3, 1.0, 0.6, 1.0),
+            WeaponType::HomingMissiles => Color::new(1.0, 0.5, 0.1, 1.0),
+            WeaponType::Railgun => Color::new(0.8, 0.5, 1.0, 1.0),
+            WeaponType::Beam => Color::new(1.0, 0.35, 0.45, 1.0),
+        }
+    }
+
+    // Faktor auf den Basis-Cooldown zwischen zwei Salven
+    pub fn cooldown_scale(&self) -> f32 {
+        match self {
+            WeaponType::Blaster => 1.0,
+            WeaponType::SpreadShot => 1.3,
+            WeaponType::RapidPulse => 0.3,
+            WeaponType::HomingMissiles => 1.8,
+            WeaponType::Railgun => 2.5,
+            WeaponType::Beam => 1.0,
+        }
+    }
+
+    // Der Beam ist ein Dauerstrahl (siehe beam.rs), alle anderen verschießen Bullets
+    pub fn fires_projectiles(&self) -> bool {
+        *self != WeaponType::Beam
+    }
+
+    // Nächste/vorherige Waffe für die Loadout-Auswahl
+    pub fn cycle(&self, step: i32) -> WeaponType {
+        let len = Self::ALL.len() as i32;
+        let index = Self::ALL.iter().position(|w| w == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(len) as usize]
+    }
+
+    // Winkelversatz der Projektile einer Salve relativ zur Zielrichtung
+    fn spread_pattern(&self, rng: &mut Rng) -> Vec<f32> {
+        match self {
+            WeaponType::Blaster | WeaponType::Railgun => vec![0.0],
+            WeaponType::SpreadShot => vec![0.0, -0.18, 0.18, -0.36, 0.36],
+            WeaponType::RapidPulse => vec![rng.gen_range(-0.05, 0.05)],
+            WeaponType::HomingMissiles => vec![-0.3, 0.3],
+            WeaponType::Beam => Vec::new(),
+        }
+    }
+
+    // Projektil-Factory: Grundwerte eines Schusses dieser Waffe, noch ohne Skills
+    pub fn projectile(&self, x: f32, y: f32, angle: f32, arena: Arena) -> Bullet {
+        let mut bullet = Bullet::new(x, y, angle, arena);
+        match self {
+            WeaponType::Blaster | WeaponType::Beam => {}
+            WeaponType::SpreadShot => {
+                bullet.damage = 1.2;
+                bullet.max_lifetime = 1.2; // Kurze Reichweite
+            }
+            WeaponType::RapidPulse => {
+                bullet.damage = 1.0;
+                bullet.size *= 0.7;
+                bullet.style = BulletStyle::Pulse;
+            }
+            WeaponType::HomingMissiles => {
+                bullet.damage = 2.0;
+                bullet.speed *= 0.55;
+                bullet.homing_strength = 1.5;
+                bullet.explosion_damage = 0.6;
+                bullet.style = BulletStyle::Missile;
+            }
+            WeaponType::Railgun => {
+                bullet.damage = 10.0;
+                bullet.speed *= 2.5;
+                bullet.pierce_count = 3;
+                bullet.remaining_pierces = 3;
+                bullet.armor_penetration = 0.3;
+                bullet.style = BulletStyle::Rail;
+            }
+        }
+        bullet.set_direction(angle);
+        bullet
+    }
+
+    // Eine Salve abfeuern. TwinCannons verdoppelt das Muster um ±0.2 rad,
+    // alle Projektile laufen durch Bullet::new_with_player_skills.
+    pub fn fire(
+        &self,
+        x: f32,
+        y: f32,
+        player: &Player,
+        arena: Arena,
+        rng: &mut Rng,
+    ) -> Vec<Bullet> {
+        let mut angles: Vec<f32> = self
+            .spread_pattern(rng)
+            .into_iter()
+            .map(|offset| player.aim_angle() + offset)
+            .collect();
+        if rng.chance(player.double_shot_chance) {
+            let base = angles.clone();
+            for spread in [-DOUBLE_SHOT_SPREAD, DOUBLE_SHOT_SPREAD] {
+                angles.extend(base.iter().map(|angle| angle + spread));
+            }
+        }
+
+        angles
+            .into_iter()
+            .map(|angle| Bullet::new_with_player_skills(*self, x, y, angle, player, arena, rng))
+            .collect()
+    }
+}
+
+// Waffen-Kiste, die während eines Runs auftaucht und die Waffe wechselt
+pub struct WeaponPickup {
+    pub x: f32,
+    pub y: f32,
+    pub size: f32,
+    pub weapon: WeaponType,
+    pub lifetime: f32,
+}
+
+impl WeaponPickup {
+    pub fn new(arena: Arena, weapon: WeaponType, rng: &mut Rng) -> Self {
+        let margin = arena.min_side() * 0.1;
+        Self {
+            x: rng.gen_range(margin, arena.width - margin),
+            y: rng.gen_range(margin, arena.height - margin),
+            size: arena.min_side() * 0.022,
+            weapon,
+            lifetime: PICKUP_LIFETIME,
+        }
+    }
+
+    // Gibt true zurück, wenn die Kiste verschwunden ist
+    pub fn update(&mut self, dt: f32) -> bool {
+        self.lifetime -= dt;
+        self.lifetime <= 0.0
+    }
+
+    pub fn collides_with(&self, player: &Player) -> bool {
+        let dx = self.x - player.x;
+        let dy = self.y - player.y;
+        (dx * dx + dy * dy).sqrt() < self.size + player.get_pickup_radius()
+    }
+
+    pub fn draw(&self) {
+        let time = get_time() as f32;
+        let color = self.weapon.color();
+        // In den letzten Sekunden blinken
+        let alpha = if self.lifetime < 3.0 {
+            0.4 + 0.6 * (time * 10.0).sin().abs()
+        } else {
+            1.0
+        };
+        let rotation = time * 40.0;
+
+        draw_poly(
+            self.x,
+            self.y,
+            6,
+            self.size * 1.6,
+            rotation,
+            Color::new(color.r, color.g, color.b, 0.15 * alpha),
+        );
+        draw_poly_lines(
+            self.x,
+            self.y,
+            6,
+            self.size,
+            rotation,
+            2.0,
+            Color::new(color.r, color.g, color.b, alpha),
+        );
+
+        // Symbol: Anzahl Punkte entspricht grob dem Schussmuster
+        if self.weapon == WeaponType::Beam {
+            draw_line(
+                self.x - self.size * 0.6,
+                self.y,
+                self.x + self.size * 0.6,
+                self.y,
+                self.size * 0.2,
+                Color::new(1.0, 1.0, 1.0, alpha),
+            );
+        }
+        let dots = match self.weapon {
+            WeaponType::Beam => 0,
+            WeaponType::Blaster | WeaponType::Railgun => 1,
+            WeaponType::HomingMissiles => 2,
+            WeaponType::RapidPulse => 3,
+            WeaponType::SpreadShot => 5,
+        };
+        for i in 0..dots {
+            let offset = (i as f32 - (dots - 1) as f32 / 2.0) * self.size * 0.35;
+            draw_circle(
+                self.x + offset,
+                self.y,
+                self.size * 0.14,
+                Color::new(1.0, 1.0, 1.0, alpha),
+            );
+        }
+    }
+}
@@ -0,0 +1,1045 @@
+use macroquad::prelude::*;
+
+use crate::adaptive::AdaptiveDirector;
+use crate::beam::{BEAM_HEAT_PER_SECOND, Beam, BeamTarget};
+use crate::boss::{BOSS_CONTACT_DAMAGE, BOSS_SCORE, Boss};
+use crate::bullet::{Bullet, Faction};
+use crate::collision::ray_intersects_circle;
+use crate::debris::Debris;
+use crate::debris_kind::{DebrisKind, load_debris_kinds, pick_debris_kind};
+use crate::difficulty::Difficulty;
+use crate::enemy::{GUNSHIP_SCORE, Gunship};
+use crate::events::{EventQueue, GameEvent, RunStats};
+use crate::explosion::Explosion;
+use crate::floating_text::FloatingText;
+use crate::input::InputState;
+use crate::items::{ItemManager, ItemType};
+use crate::player::Player;
+use crate::rng::Rng;
+use crate::spatial_grid::SpatialGrid;
+use crate::star::Star;
+use crate::waves::{Formation, Side, SpawnEntry, WaveAction, WaveDirector, load_stage_script};
+use crate::weapon::{WeaponPickup, WeaponType};
+
+// Feste Tickrate der Simulation, unabhängig von der Bildwiederholrate
+pub const TICK_RATE: f32 = 120.0;
+pub const FIXED_DT: f32 = 1.0 / TICK_RATE;
+
+// Logische Größe des Spielfelds. Unabhängig von Fenster und Monitor,
+// das Bild wird beim Zeichnen nur skaliert.
+pub const ARENA_WIDTH: f32 = 1920.0;
+pub const ARENA_HEIGHT: f32 = 1080.0;
+
+// Zellgröße des Kollisionsrasters, etwa der Durchmesser großer Debris
+const GRID_CELL_SIZE: f32 = 128.0;
+
+// Kanonenboote tauchen erst nach einer Weile auf
+const GUNSHIP_FIRST_SPAWN: f32 = 20.0;
+const GUNSHIP_SPAWN_INTERVAL: f32 = 15.0;
+const MAX_GUNSHIPS: usize = 4;
+
+// Überlebenszeit bis zum nächsten Boss (läuft nicht, solange einer aktiv ist)
+const BOSS_INTERVAL: f32 = 180.0;
+
+// Waffen-Kisten: höchstens eine gleichzeitig, in festen Abständen
+const WEAPON_DROP_INTERVAL: f32 = 30.0;
+
+// Rückstoß bei Kollisionen, relativ zur Grundgeschwindigkeit des Spielers
+const KNOCKBACK_STRENGTH: f32 = 1.2;
+
+// Größe des Spielfelds, in dem die Simulation läuft
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Arena {
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Arena {
+    pub fn new(width: f32, height: f32) -> Self {
+        Self { width, height }
+    }
+
+    pub fn logical() -> Self {
+        Self::new(ARENA_WIDTH, ARENA_HEIGHT)
+    }
+
+    // Kamera, die das Spielfeld mit Letterboxing ins Fenster einpasst
+    pub fn camera(&self) -> Camera2D {
+        let scale = (screen_width() / self.width).min(screen_height() / self.height);
+        let view_w = self.width * scale;
+        let view_h = self.height * scale;
+
+        let mut camera = Camera2D::from_display_rect(Rect::new(0.0, 0.0, self.width, self.height));
+        camera.viewport = Some((
+            ((screen_width() - view_w) / 2.0) as i32,
+            ((screen_height() - view_h) / 2.0) as i32,
+            view_w as i32,
+            view_h as i32,
+        ));
+        camera
+    }
+
+    pub fn min_side(&self) -> f32 {
+        self.width.min(self.height)
+    }
+
+    pub fn max_side(&self) -> f32 {
+        self.width.max(self.height)
+    }
+}
+
+// Kompletter Spielzustand eines Runs. Enthält keine Aufrufe von Fenster-,
+// Zeit- oder Eingabefunktionen und kann daher auch ohne Fenster laufen.
+pub struct World {
+    pub arena: Arena,
+    pub seed: u32,
+    pub difficulty: Difficulty,
+    pub debris_kinds: Vec<DebrisKind>,
+    pub rng: Rng,    // Gameplay-Zufall (Spawns, Items, Crits)
+    pub fx_rng: Rng, // Kosmetischer Zufall (Sterne, Explosionen)
+    pub player: Player,
+    pub bullets: Vec<Bullet>,
+    pub beam: Beam, // Dauerstrahl der Beam-Waffe
+    pub debris: Vec<Debris>,
+    pub gunships: Vec<Gunship>,
+    pub boss: Option<Boss>,
+    pub floating_texts: Vec<FloatingText>,
+    pub explosions: Vec<Explosion>,
+    pub stars: Vec<Star>,
+    pub item_manager: ItemManager,
+    pub weapon_pickups: Vec<WeaponPickup>,
+    pub debris_grid: SpatialGrid, // Raster über `debris`, wird pro Tick neu gebaut
+    pub events: EventQueue,       // Ereignisse des laufenden Ticks
+    pub stats: RunStats,
+    pub score: i32,
+    pub director: WaveDirector,             // Wellen aus assets/waves.json
+    pub adaptive: Option<AdaptiveDirector>, // Optional: Tempo nach Leistung des Spielers
+    pub spawn_timer: f32,
+    pub spawn_rate: f32, // Sekunden zwischen Spawns
+    pub gunship_timer: f32,
+    pub boss_timer: f32,
+    pub bosses_spawned: u32,
+    pub next_entity_id: u32,
+    pub weapon_drop_timer: f32,
+}
+
+impl World {
+    pub fn new(arena: Arena, seed: u32, difficulty: Difficulty) -> Self {
+        let star_count = ((arena.width * arena.height) / 8000.0) as usize;
+        let mut fx_rng = Rng::cosmetic(seed);
+        let stars = (0..star_count)
+            .map(|_| Star::new(arena, &mut fx_rng))
+            .collect();
+
+        // Schwierigkeit auf Debris, Wellen, Spieler und Items anwenden
+        let preset = difficulty.preset();
+        let mut debris_kinds = load_debris_kinds();
+        for kind in debris_kinds.iter_mut() {
+            kind.speed_range.0 *= preset.debris_speed_scale;
+            kind.speed_range.1 *= preset.debris_speed_scale;
+            kind.hp_scale *= preset.debris_hp_scale;
+        }
+        // Der Wellenablauf verweist per Name auf die Archetypen
+        let mut script = load_stage_script(&debris_kinds);
+        script.apply_difficulty(&preset);
+        let mut player = Player::new(arena);
+        player.max_hp = preset.player_max_hp;
+        player.hp = player.max_hp;
+
+        Self {
+            arena,
+            seed,
+            difficulty,
+            debris_kinds,
+            rng: Rng::gameplay(seed),
+            fx_rng,
+            player,
+            bullets: Vec::new(),
+            debris: Vec::new(),
+            gunships: Vec::new(),
+            boss: None,
+            floating_texts: Vec::new(),
+            explosions: Vec::new(),
+            stars,
+            item_manager: ItemManager::new(preset.item_spawn_interval),
+            weapon_pickups: Vec::new(),
+            beam: Beam::new(),
+            debris_grid: SpatialGrid::new(GRID_CELL_SIZE),
+            events: EventQueue::new(),
+            stats: RunStats::default(),
+            score: 0,
+            director: WaveDirector::new(script),
+            adaptive: None,
+            spawn_timer: 0.0,
+            spawn_rate: 1.0,
+            gunship_timer: 0.0,
+            boss_timer: 0.0,
+            bosses_spawned: 0,
+            next_entity_id: 1,
+            weapon_drop_timer: 0.0,
+        }
+    }
+
+    // Einen Simulationsschritt ausführen. Gibt true zurück, wenn der Run vorbei ist.
+    pub fn update(&mut self, dt: f32, input: &InputState) -> bool {
+        let arena = self.arena;
+
+        // Sterne updaten
+        for s in self.stars.iter_mut() {
+            s.update(dt, arena, &mut self.fx_rng);
+        }
+
+        // Adaptiver Director bewertet die letzten Sekunden und passt das Tempo an
+        let mut adaptive_speed = 1.0;
+        if let Some(adaptive) = self.adaptive.as_mut() {
+            adaptive.update(dt, &self.player);
+            self.item_manager.spawn_interval_scale = adaptive.item_interval_scale();
+            adaptive_speed = adaptive.debris_speed_scale();
+        }
+
+        // Items updaten (mit Spieler für Magnet-Effekt)
+        self.item_manager
+            .update(dt, &self.player, arena, &mut self.rng);
+
+        // Item-Pickups prüfen
+        self.item_manager
+            .check_pickups(&mut self.player, &mut self.events);
+
+        // Waffen-Kisten: eine andere Waffe als die aktuelle anbieten
+        if self.weapon_pickups.is_empty() {
+            self.weapon_drop_timer += dt;
+            if self.weapon_drop_timer >= WEAPON_DROP_INTERVAL {
+                let choices: Vec<WeaponType> = WeaponType::ALL
+                    .into_iter()
+                    .filter(|w| *w != self.player.weapon)
+                    .collect();
+                let weapon = choices[self.rng.gen_range(0, choices.len())];
+                self.weapon_pickups
+                    .push(WeaponPickup::new(arena, weapon, &mut self.rng));
+                self.weapon_drop_timer = 0.0;
+            }
+        }
+        let player = &mut self.player;
+        let events = &mut self.events;
+        self.weapon_pickups.retain_mut(|pickup| {
+            if pickup.collides_with(player) {
+                player.weapon = pickup.weapon;
+                events.push(GameEvent::WeaponPickedUp {
+                    weapon: pickup.weapon,
+                    x: pickup.x,
+                    y: pickup.y,
+                });
+                return false;
+            }
+            !pickup.update(dt)
+        });
+
+        // Spieler updaten
+        self.player.update(
+            dt,
+            input,
+            arena,
+            &mut self.bullets,
+            &mut self.rng,
+            &mut self.events,
+        );
+
+        // Wellen: Tempo, gescriptete Formationen und Items
+        let mut wave_actions = Vec::new();
+        self.director.update(dt, &mut wave_actions);
+        for action in wave_actions {
+            self.run_wave_action(action);
+        }
+        let background_spawns = self.director.spawn_rate();
+        if let Some(rate) = background_spawns {
+            self.spawn_rate = rate;
+        }
+
+        // Alle paar Minuten ein Boss als Meilenstein
+        if self.boss.is_none() {
+            self.boss_timer += dt;
+            if self.boss_timer >= BOSS_INTERVAL {
+                self.bosses_spawned += 1;
+                self.boss = Some(Boss::new(arena, self.bosses_spawned));
+                self.boss_timer = 0.0;
+            }
+        }
+
+        // Effekt-basierte Spawn-Rate Modifikation
+        let mut effective_spawn_rate = self.spawn_rate;
+        if let Some(adaptive) = &self.adaptive {
+            effective_spawn_rate *= adaptive.spawn_rate_scale();
+        }
+
+        // SlowMotion und TimeFreeze beeinflussen Gegner-Spawn
+        if self.player.has_effect(&ItemType::SlowMotion) {
+            effective_spawn_rate *= 1.5; // Langsameres Spawning
+        }
+        if self.player.has_effect(&ItemType::TimeFreeze) {
+            effective_spawn_rate *= 3.0; // Sehr langsameres Spawning
+        }
+
+        // Neuen Schrott spawnen (nicht in Wellenpausen)
+        if background_spawns.is_some() {
+            self.spawn_timer += dt;
+        }
+        if self.spawn_timer > effective_spawn_rate {
+            self.debris
+                .push(Debris::new(arena, &self.debris_kinds, &mut self.rng));
+            self.spawn_timer = 0.0;
+        }
+
+        // Kanonenboote spawnen, mit der Zeit dürfen es mehr gleichzeitig sein
+        self.gunship_timer += dt;
+        let max_gunships = (1 + (self.player.elapsed_time / 60.0) as usize).min(MAX_GUNSHIPS);
+        if self.player.elapsed_time > GUNSHIP_FIRST_SPAWN
+            && self.gunship_timer > GUNSHIP_SPAWN_INTERVAL
+            && self.gunships.len() < max_gunships
+        {
+            self.gunships.push(Gunship::new(arena, &mut self.rng));
+            self.gunship_timer = 0.0;
+        }
+
+        // Schrott updaten mit Effekt-Modifikatoren
+        let mut debris_speed_multiplier = 1.0;
+
+        if self.player.has_effect(&ItemType::SlowMotion) {
+            debris_speed_multiplier = 0.3; // 30% Geschwindigkeit
+        }
+        if self.player.has_effect(&ItemType::TimeFreeze) {
+            debris_speed_multiplier = 0.0; // Komplett eingefroren
+        }
+
+        // Debris-Geschwindigkeit modifizieren
+        for debris_piece in self.debris.iter_mut() {
+            debris_piece.speed_multiplier = debris_speed_multiplier * adaptive_speed;
+        }
+
+        let events = &mut self.events;
+        self.debris.retain_mut(|d| {
+            if d.update(dt) {
+                events.push(GameEvent::DebrisDestroyed {
+                    x: d.x,
+                    y: d.y,
+                    size: d.size,
+                    velocity_x: d.velocity_x,
+                    velocity_y: d.velocity_y,
+                    kind: d.kind,
+                    generation: d.generation,
+                });
+                false
+            } else {
+                true
+            }
+        });
+
+        // Boss bewegen und angreifen, herbeigerufene Debris spawnen
+        let player_pos = self.player.get_position();
+        if let Some(boss) = self.boss.as_mut() {
+            boss.time_scale = debris_speed_multiplier;
+            if boss.update(dt, player_pos, arena, &mut self.bullets, &mut self.rng) {
+                self.events.push(GameEvent::BossDefeated {
+                    x: boss.x,
+                    y: boss.y,
+                    size: boss.size,
+                    number: boss.number,
+                });
+                self.boss = None;
+            } else {
+                for _ in 0..boss.take_summons() {
+                    let kind_index = pick_debris_kind(&self.debris_kinds, &mut self.rng);
+                    let kind = &self.debris_kinds[kind_index];
+                    let size =
+                        arena.min_side() * self.rng.gen_range(kind.size_range.0, kind.size_range.1);
+                    // Richtung Spieler, leicht gestreut
+                    let angle = (player_pos.y - boss.y).atan2(player_pos.x - boss.x)
+                        + self.rng.gen_range(-0.5, 0.5);
+                    let speed = arena.min_side()
+                        * self.rng.gen_range(kind.speed_range.0, kind.speed_range.1);
+                    let direction = Vec2::new(angle.cos(), angle.sin());
+                    self.debris.push(Debris::new_fragment(
+                        arena,
+                        kind,
+                        kind_index,
+                        Vec2::new(boss.x, boss.y) + direction * (boss.size + size),
+                        direction * speed,
+                        size,
+                        &mut self.rng,
+                    ));
+                }
+            }
+        }
+
+        self.rebuild_debris_grid();
+
+        // Kanonenboote bewegen, zielen und schießen
+        let events = &mut self.events;
+        let bullets = &mut self.bullets;
+        let rng = &mut self.rng;
+        self.gunships.retain_mut(|g| {
+            g.time_scale = debris_speed_multiplier;
+            if g.update(dt, player_pos, arena, bullets, rng) {
+                events.push(GameEvent::GunshipDestroyed {
+                    x: g.x,
+                    y: g.y,
+                    size: g.size,
+                });
+                false
+            } else {
+                true
+            }
+        });
+
+        // Kollision mit Spieler (außer bei PhaseShift)
+        if !self.player.can_phase_through {
+            let mut candidates = Vec::new();
+            self.debris_grid.query_circle(
+                self.player.x,
+                self.player.y,
+                self.player.size,
+                &mut candidates,
+            );
+
+            let mut hits = Vec::new();
+            for i in candidates {
+                if self.debris[i].collides_with(&self.player) {
+                    // Stoß weg vom Debris, größere Brocken stoßen stärker
+                    let d = &self.debris[i];
+                    let away = (self.player.get_position() - Vec2::new(d.x, d.y))
+                        .normalize_or(Vec2::new(0.0, 1.0));
+                    let mass = (d.size / self.player.base_size).clamp(0.5, 2.0);
+                    let impulse = away * self.player.base_speed * KNOCKBACK_STRENGTH * mass
+                        + Vec2::new(d.velocity_x, d.velocity_y) * 0.3;
+                    self.player.apply_knockback(impulse);
+
+                    let hp_before = self.player.hp;
+                    self.player.take_debris_damage(self.debris[i].damage);
+                    if self.player.hp < hp_before {
+                        self.events.push(GameEvent::PlayerDamaged {
+                            damage: hp_before - self.player.hp,
+                        });
+                    }
+                    hits.push(i);
+                }
+            }
+
+            // Getroffene Debris entfernen (von hinten, damit die Indizes stimmen)
+            if !hits.is_empty() {
+                for i in hits.into_iter().rev() {
+                    self.debris.remove(i);
+                }
+                self.rebuild_debris_grid();
+            }
+
+            // Rammen durch den Boss
+            if let Some(boss) = self.boss.as_mut()
+                && boss.try_contact(player_pos, self.player.size)
+            {
+                let away =
+                    (player_pos - Vec2::new(boss.x, boss.y)).normalize_or(Vec2::new(0.0, 1.0));
+                self.player
+                    .apply_knockback(away * self.player.base_speed * KNOCKBACK_STRENGTH * 2.0);
+
+                let hp_before = self.player.hp;
+                self.player.take_damage(BOSS_CONTACT_DAMAGE);
+                if self.player.hp < hp_before {
+                    self.events.push(GameEvent::PlayerDamaged {
+                        damage: hp_before - self.player.hp,
+                    });
+                }
+            }
+        }
+
+        if self.player.is_destroyed() {
+            self.events.push(GameEvent::RunEnded { score: self.score });
+            self.dispatch_events();
+            return true; // Game over
+        }
+
+        // Bullets updaten
+        for b in self.bullets.iter_mut() {
+            b.update(dt, &self.debris, &self.debris_grid);
+        }
+
+        // Update der floating texts
+        for ft in self.floating_texts.iter_mut() {
+            ft.update(dt);
+        }
+        self.floating_texts.retain(|ft| !ft.is_dead());
+
+        // Bullet <-> Debris Kollision
+        self.assign_entity_ids();
+        Bullet::handle_collisions(
+            &mut self.bullets,
+            &mut self.debris,
+            &mut self.debris_grid,
+            &mut self.events,
+        );
+        Bullet::handle_gunship_collisions(&mut self.bullets, &mut self.gunships, &mut self.events);
+        if let Some(boss) = self.boss.as_mut() {
+            Bullet::handle_boss_collisions(&mut self.bullets, boss, &mut self.events);
+        }
+
+        // Beam gegen Debris (über das frische Raster), Kanonenboote und Boss
+        let firing_beam = input.shoot && self.player.weapon == WeaponType::Beam;
+        self.update_beam(dt, firing_beam);
+
+        // Gegnerische Schüsse gegen den Spieler (außer bei PhaseShift)
+        if !self.player.can_phase_through {
+            let player = &mut self.player;
+            let events = &mut self.events;
+            self.bullets.retain(|b| {
+                if b.faction == Faction::Enemy && b.hits_circle(player.x, player.y, player.size) {
+                    let hp_before = player.hp;
+                    player.take_damage(b.damage);
+                    if player.hp < hp_before {
+                        events.push(GameEvent::PlayerDamaged {
+                            damage: hp_before - player.hp,
+                        });
+                    }
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+
+        // Update-Loop für Explosionen
+        for explosion in self.explosions.iter_mut() {
+            explosion.update(dt);
+        }
+        // Entferne fertig animierte Explosionen
+        self.explosions.retain(|e| !e.is_finished());
+
+        // Offscreen-Bullets entfernen
+        self.bullets.retain(|b| !b.is_off_screen(arena));
+
+        // Schrott außerhalb des Bildschirms entfernen
+        let events = &mut self.events;
+        self.debris.retain(|d| {
+            if d.is_off_screen(arena) {
+                events.push(GameEvent::DebrisEscaped {
+                    x: d.x,
+                    y: d.y,
+                    kind: d.kind,
+                });
+                false
+            } else {
+                true
+            }
+        });
+
+        self.dispatch_events();
+
+        false // Kein Game over
+    }
+
+    // Strahl ausrichten, Ziele per Raycast sammeln und bei jedem Puls Schaden austeilen
+    fn update_beam(&mut self, dt: f32, firing: bool) {
+        // Mit Hitzemodell heizt auch der Beam auf und ist bei Überhitzung gesperrt
+        let firing = firing && !self.player.is_overheated();
+        self.beam.update(dt, firing, &self.player, self.arena);
+        if !self.beam.active {
+            return;
+        }
+        self.player.add_heat(BEAM_HEAT_PER_SECOND * dt);
+
+        let (origin, dir) = (self.beam.origin, self.beam.direction);
+        let mut candidates = Vec::new();
+        self.debris_grid
+            .query_ray(origin, dir, self.beam.length, &mut candidates);
+        for i in candidates {
+            if let Some(t) = self.debris[i].ray_hit(origin, dir) {
+                self.beam.add_hit(t, BeamTarget::Debris(i));
+            }
+        }
+        for (i, gunship) in self.gunships.iter().enumerate() {
+            let center = Vec2::new(gunship.x, gunship.y);
+            if let Some(t) = ray_intersects_circle(origin, dir, center, gunship.size) {
+                self.beam.add_hit(t, BeamTarget::Gunship(i));
+            }
+        }
+        if let Some(boss) = &self.boss
+            && let Some(t) =
+                ray_intersects_circle(origin, dir, Vec2::new(boss.x, boss.y), boss.size)
+        {
+            self.beam.add_hit(t, BeamTarget::Boss);
+        }
+        self.beam.finish_hits(&self.player);
+
+        if self.beam.pulse {
+            let hits = std::mem::take(&mut self.beam.hits);
+            for &(t, target) in &hits {
+                let armor = match target {
+                    BeamTarget::Debris(i) => self.debris[i].armor,
+                    BeamTarget::Gunship(i) => self.gunships[i].armor,
+                    BeamTarget::Boss => self.boss.as_ref().map_or(0.0, |b| b.armor),
+                };
+                let damage =
+                    self.beam
+                        .pulse_damage(t, armor, &self.player, &mut self.rng, &mut self.events);
+                match target {
+                    BeamTarget::Debris(i) => {
+                        self.debris[i].take_damage(damage);
+                    }
+                    BeamTarget::Gunship(i) => {
+                        self.gunships[i].take_damage(damage);
+                    }
+                    BeamTarget::Boss => {
+                        if let Some(boss) = self.boss.as_mut() {
+                            boss.take_damage(damage);
+                        }
+                    }
+                }
+            }
+            self.beam.hits = hits;
+        }
+
+        // Funken an den Trefferpunkten der Pulse
+        for spark in &self.beam.sparks {
+            let back = -self.beam.direction * 120.0;
+            self.explosions.push(Explosion::new(
+                spark.x,
+                spark.y,
+                6.0,
+                back.x,
+                back.y,
+                &[
+                    Color::new(1.0, 0.5, 0.3, 1.0),
+                    Color::new(1.0, 0.85, 0.5, 1.0),
+                    Color::new(1.0, 0.3, 0.4, 1.0),
+                ],
+                &mut self.fx_rng,
+            ));
+        }
+    }
+
+    // Ereignisse des Ticks an alle Subscriber verteilen
+    fn dispatch_events(&mut self) {
+        for event in self.events.drain() {
+            self.apply_scoring(&event);
+            self.spawn_fragments(&event);
+            self.spawn_loot(&event);
+            self.spawn_feedback(&event);
+            self.apply_skill_triggers(&event);
+            self.stats.record(&event);
+            if let Some(adaptive) = self.adaptive.as_mut() {
+                adaptive.record(&event);
+            }
+        }
+    }
+
+    // Punkte für zerstörte und entkommene Debris
+    fn apply_scoring(&mut self, event: &GameEvent) {
+        let multiplier = self.player.points_multiplier;
+        match event {
+            GameEvent::DebrisDestroyed {
+                x,
+                y,
+                kind,
+                generation,
+                ..
+            } => {
+                // Bruchstücke geben pro Generation nur die Hälfte
+                let base_points =
+                    self.debris_kinds[*kind].score as f32 * 0.5f32.powi(*generation as i32);
+                let points = (base_points * multiplier).round() as i32;
+                self.score += points;
+                self.floating_texts.push(FloatingText::new(*x, *y, points));
+            }
+            GameEvent::BossDefeated { x, y, number, .. } => {
+                let points = (BOSS_SCORE as f32 * *number as f32 * multiplier).round() as i32;
+                self.score += points;
+                self.floating_texts.push(FloatingText::new_with_text(
+                    *x,
+                    *y,
+                    format!("BOSS DEFEATED! +{}", points),
+                    Color::new(1.0, 0.84, 0.0, 1.0),
+                ));
+            }
+            GameEvent::GunshipDestroyed { x, y, .. } => {
+                let points = (GUNSHIP_SCORE as f32 * multiplier).round() as i32;
+                self.score += points;
+                self.floating_texts.push(FloatingText::new(*x, *y, points));
+            }
+            GameEvent::DebrisEscaped { x, y, kind } => {
+                let base_points = self.debris_kinds[*kind].escape_score;
+                let points = (base_points as f32 * multiplier) as i32;
+                self.score += points;
+
+                // Floating text für Bonus-Punkte
+                if multiplier > 1.0 {
+                    self.floating_texts.push(FloatingText::new_with_text(
+                        *x,
+                        *y,
+                        format!("+{} ({}x)", points, multiplier as i32),
+                        Color::new(1.0, 1.0, 0.0, 1.0),
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Große Debris zerbrechen in Bruchstücke, die den Schwung mitnehmen
+    fn spawn_fragments(&mut self, event: &GameEvent) {
+        let GameEvent::DebrisDestroyed {
+            x,
+            y,
+            size,
+            velocity_x,
+            velocity_y,
+            kind,
+            generation,
+        } = event
+        else {
+            return;
+        };
+
+        let debris_kind = &self.debris_kinds[*kind];
+        if *size < debris_kind.split_min_size * self.arena.min_side() {
+            return; // Zu klein, vergeht einfach
+        }
+
+        let (min_count, max_count) = debris_kind.fragment_count;
+        let count = self.rng.gen_range(min_count, max_count + 1);
+        let base_angle = velocity_y.atan2(*velocity_x);
+        let base_speed = (velocity_x * velocity_x + velocity_y * velocity_y).sqrt();
+
+        for i in 0..count {
+            // Gleichmäßig aufgefächert plus etwas Zufall
+            let spread = ((i as f32 + 0.5) / count as f32 - 0.5) * 1.2;
+            let angle = base_angle + spread + self.rng.gen_range(-0.15, 0.15);
+            let speed = base_speed * self.rng.gen_range(1.1, 1.4);
+            let fragment_size = size * self.rng.gen_range(0.45, 0.6);
+            let direction = Vec2::new(angle.cos(), angle.sin());
+
+            let mut fragment = Debris::new_fragment(
+                self.arena,
+                debris_kind,
+                *kind,
+                Vec2::new(*x, *y) + direction * fragment_size,
+                direction * speed,
+                fragment_size,
+                &mut self.rng,
+            );
+            fragment.generation = generation + 1;
+            self.debris.push(fragment);
+        }
+    }
+
+    fn run_wave_action(&mut self, action: WaveAction) {
+        match action {
+            WaveAction::WaveStarted { number } => {
+                self.events.push(GameEvent::WaveStarted { number });
+            }
+            WaveAction::SpawnGroup(entry) => self.spawn_formation(&entry),
+            WaveAction::DropItem(item_type) => {
+                self.item_manager
+                    .spawn_item_of_type(item_type, self.arena, &mut self.rng);
+            }
+        }
+    }
+
+    // Gescriptete Debris-Gruppe spawnen
+    fn spawn_formation(&mut self, entry: &SpawnEntry) {
+        let arena = self.arena;
+        let named_kind = entry
+            .kind
+            .as_ref()
+            .and_then(|name| self.debris_kinds.iter().position(|k| &k.name == name));
+
+        if entry.formation == Formation::Random {
+            for _ in 0..entry.count {
+                let kind_index = named_kind
+                    .unwrap_or_else(|| pick_debris_kind(&self.debris_kinds, &mut self.rng));
+                self.debris.push(Debris::new_of_kind(
+                    arena,
+                    &self.debris_kinds,
+                    kind_index,
+                    &mut self.rng,
+                ));
+            }
+            return;
+        }
+
+        // Formationen fliegen geschlossen: gleicher Archetyp, gleiche Größe und Richtung
+        let kind_index =
+            named_kind.unwrap_or_else(|| pick_debris_kind(&self.debris_kinds, &mut self.rng));
+        let kind = &self.debris_kinds[kind_index];
+        let size = arena.min_side() * self.rng.gen_range(kind.size_range.0, kind.size_range.1);
+        let speed = arena.min_side() * self.rng.gen_range(kind.speed_range.0, kind.speed_range.1);
+
+        for i in 0..entry.count {
+            // Anteil entlang der Seite, gleichmäßig verteilt
+            let t = (i + 1) as f32 / (entry.count + 1) as f32;
+            let (position, direction) = match entry.side {
+                Side::Top => (Vec2::new(arena.width * t, -size), Vec2::new(0.0, 1.0)),
+                Side::Bottom => (
+                    Vec2::new(arena.width * t, arena.height + size),
+                    Vec2::new(0.0, -1.0),
+                ),
+                Side::Left => (Vec2::new(-size, arena.height * t), Vec2::new(1.0, 0.0)),
+                Side::Right => (
+                    Vec2::new(arena.width + size, arena.height * t),
+                    Vec2::new(-1.0, 0.0),
+                ),
+            };
+            // Beim Keil ist die Mitte schneller und fliegt voraus
+            let speed_factor = match entry.formation {
+                Formation::Wedge => 1.0 + 0.4 * (1.0 - (t - 0.5).abs() * 2.0),
+                _ => 1.0,
+            };
+
+            self.debris.push(Debris::new_fragment(
+                arena,
+                kind,
+                kind_index,
+                position,
+                direction * speed * speed_factor,
+                size,
+                &mut self.rng,
+            ));
+        }
+    }
+
+    // Garantierte Beute eines besiegten Bosses
+    fn spawn_loot(&mut self, event: &GameEvent) {
+        if let GameEvent::BossDefeated { x, y, .. } = event {
+            self.item_manager
+                .drop_item(Vec2::new(*x, *y), &mut self.rng);
+        }
+    }
+
+    // Explosionen und Hinweistexte
+    fn spawn_feedback(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::DebrisDestroyed {
+                x,
+                y,
+                size,
+                velocity_x,
+                velocity_y,
+                kind,
+                ..
+            } => {
+                self.explosions.push(Explosion::new(
+                    *x,
+                    *y,
+                    *size,
+                    *velocity_x,
+                    *velocity_y,
+                    &self.debris_kinds[*kind].explosion_colors(),
+                    &mut self.fx_rng,
+                ));
+            }
+            GameEvent::BulletHit {
+                x, y, deflected, ..
+            } if *deflected => {
+                self.floating_texts.push(FloatingText::new_with_text(
+                    *x,
+                    *y,
+                    "DEFLECTED".to_string(),
+                    Color::new(0.7, 0.75, 0.8, 1.0),
+                ));
+            }
+            GameEvent::BulletHit {
+                x,
+                y,
+                damage,
+                is_critical,
+                ..
+            } if *is_critical => {
+                self.floating_texts.push(FloatingText::new_with_text(
+                    *x,
+                    *y,
+                    format!("CRIT {:.1}", damage),
+                    Color::new(1.0, 0.35, 0.25, 1.0),
+                ));
+            }
+            GameEvent::WaveStarted { number } => {
+                self.floating_texts.push(FloatingText::new_with_text(
+                    self.arena.width / 2.0,
+                    self.arena.height * 0.4,
+                    format!("WAVE {}", number),
+                    Color::new(0.6, 0.9, 1.0, 1.0),
+                ));
+            }
+            GameEvent::BossDefeated { x, y, size, .. } => {
+                self.explosions.push(Explosion::new(
+                    *x,
+                    *y,
+                    *size,
+                    0.0,
+                    0.0,
+                    &Boss::explosion_colors(),
+                    &mut self.fx_rng,
+                ));
+            }
+            GameEvent::GunshipDestroyed { x, y, size } => {
+                self.explosions.push(Explosion::new(
+                    *x,
+                    *y,
+                    *size,
+                    0.0,
+                    0.0,
+                    &Gunship::explosion_colors(),
+                    &mut self.fx_rng,
+                ));
+            }
+            GameEvent::WeaponPickedUp { weapon, x, y } => {
+                self.floating_texts.push(FloatingText::new_with_text(
+                    *x,
+                    *y,
+                    weapon.label().to_uppercase(),
+                    weapon.color(),
+                ));
+            }
+            GameEvent::ItemPickedUp { item_type, x, y } => {
+                self.floating_texts.push(FloatingText::new_with_text(
+                    *x,
+                    *y,
+                    item_type.pickup_text().to_string(),
+                    Color::new(1.0, 1.0, 0.0, 1.0),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    // Skills, die auf Ereignisse reagieren
+    fn apply_skill_triggers(&mut self, event: &GameEvent) {
+        if let GameEvent::ItemPickedUp { x, y, .. } = event
+            && self.player.on_item_pickup()
+        {
+            // Golden Touch: Bonuspunkte bei jedem 10. Pickup
+            let points = (500.0 * self.player.points_multiplier).round() as i32;
+            self.score += points;
+            self.floating_texts.push(FloatingText::new_with_text(
+                *x,
+                *y - 20.0,
+                format!("GOLDEN TOUCH +{}", points),
+                GOLD,
+            ));
+        }
+    }
+
+    // Füllt die Welt für den Stresstest ("--stress") mit Debris und Bullets auf
+    pub fn fill_stress_scene(&mut self, debris_count: usize, bullet_count: usize) {
+        let arena = self.arena;
+        while self.debris.len() < debris_count {
+            let mut d = Debris::new(arena, &self.debris_kinds, &mut self.rng);
+            // Mitten ins Spielfeld statt an den Rand
+            d.x = self.rng.gen_range(0.0, arena.width);
+            d.y = self.rng.gen_range(0.0, arena.height);
+            d.prev_x = d.x;
+            d.prev_y = d.y;
+            self.debris.push(d);
+        }
+        while self.bullets.len() < bullet_count {
+            let x = self.rng.gen_range(0.0, arena.width);
+            let y = self.rng.gen_range(0.0, arena.height);
+            let angle = self.rng.gen_range(0.0, std::f32::consts::TAU);
+            let mut b = Bullet::new(x, y, angle, arena);
+            // Alle teuren Pfade aktiv: Homing, Explosionen, Pierce
+            b.homing_strength = 0.5;
+            b.explosion_damage = 0.5;
+            b.pierce_count = 2;
+            b.remaining_pierces = 2;
+            self.bullets.push(b);
+        }
+    }
+
+    // Neue Debris und Kanonenboote bekommen eine eindeutige Id, damit
+    // durchschlagende Schüsse ihre Ziele wiedererkennen
+    fn assign_entity_ids(&mut self) {
+        let debris_ids = self.debris.iter_mut().map(|d| &mut d.id);
+        let gunship_ids = self.gunships.iter_mut().map(|g| &mut g.id);
+        for id in debris_ids.chain(gunship_ids).filter(|id| **id == 0) {
+            *id = self.next_entity_id;
+            self.next_entity_id += 1;
+        }
+    }
+
+    fn rebuild_debris_grid(&mut self) {
+        self.debris_grid.rebuild(
+            self.arena,
+            self.debris.iter().map(|d| (d.x, d.y, d.collision_radius())),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debris_collision_damages_player() {
+        let mut world = World::new(Arena::new(1920.0, 1080.0), 42, Difficulty::Normal);
+        let mut debris = Debris::new(world.arena, &[DebrisKind::scrap()], &mut Rng::new(1));
+        debris.x = world.player.x;
+        debris.y = world.player.y;
+        debris.velocity_x = 0.0;
+        debris.velocity_y = 0.0;
+        world.debris.push(debris);
+
+        let game_over = world.update(1.0 / 60.0, &InputState::default());
+        assert!(!game_over);
+        assert!(world.player.hp < world.player.max_hp);
+        assert!(world.debris.is_empty());
+    }
+
+    #[test]
+    fn beam_heats_up_and_is_locked_when_overheated() {
+        let mut world = World::new(Arena::new(1920.0, 1080.0), 42, Difficulty::Normal);
+        world.player.weapon = WeaponType::Beam;
+        world.player.heat_enabled = true;
+        let shoot = InputState {
+            shoot: true,
+            ..Default::default()
+        };
+
+        world.update(1.0 / 120.0, &shoot);
+        assert!(world.beam.active);
+        assert!(world.player.heat > 0.0);
+
+        world.player.add_heat(1.0);
+        world.update(1.0 / 120.0, &shoot);
+        assert!(!world.beam.active);
+    }
+
+    fn destroyed(world: &World, size: f32, generation: u8) -> GameEvent {
+        GameEvent::DebrisDestroyed {
+            x: world.arena.width / 2.0,
+            y: world.arena.height / 2.0,
+            size,
+            velocity_x: 100.0,
+            velocity_y: 0.0,
+            kind: 0,
+            generation,
+        }
+    }
+
+    #[test]
+    fn large_debris_splits_into_smaller_fragments() {
+        let mut world = World::new(Arena::new(1920.0, 1080.0), 42, Difficulty::Normal);
+        world.debris_kinds = vec![DebrisKind::scrap()];
+        let split_size = world.debris_kinds[0].split_min_size * world.arena.min_side();
+
+        world.events.push(destroyed(&world, split_size * 0.9, 0));
+        world.dispatch_events();
+        assert!(world.debris.is_empty());
+
+        let size = split_size * 1.5;
+        world.events.push(destroyed(&world, size, 1));
+        world.dispatch_events();
+        let (min_count, max_count) = world.debris_kinds[0].fragment_count;
+        assert!((min_count as usize..=max_count as usize).contains(&world.debris.len()));
+        for fragment in &world.debris {
+            assert!(fragment.size < size);
+            assert_eq!(fragment.generation, 2);
+            // Schwung geht in Flugrichtung weiter
+            assert!(fragment.velocity_x > 0.0);
+        }
+    }
+}